@@ -0,0 +1,98 @@
+//! Derive macro for the `ConstantTimeEq` trait from the `constant_time_eq` crate.
+//!
+//! Use it through the "derive" feature of the `constant_time_eq` crate, instead of depending on
+//! this crate directly.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Field, Index, Member, parse_macro_input, parse_quote};
+
+/// Derives `ConstantTimeEq` for a struct, comparing all its fields in constant time.
+///
+/// Every field is compared with `ConstantTimeEq::ct_eq`, and the results are combined without
+/// short-circuiting, so the comparison does not stop at the first field which differs. Fields
+/// marked with `#[ct_eq(skip)]` are not compared.
+#[proc_macro_derive(ConstantTimeEq, attributes(ct_eq))]
+pub fn derive_constant_time_eq(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(mut input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "ConstantTimeEq can only be derived for structs",
+        ));
+    };
+
+    let mut members = Vec::new();
+    for (i, field) in data.fields.iter().enumerate() {
+        if is_skipped(field)? {
+            continue;
+        }
+        members.push(match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        });
+    }
+
+    let body = if members.is_empty() {
+        quote! {
+            let _ = other;
+            true
+        }
+    } else {
+        // The masks are combined through an optimizer barrier, so the compiler cannot skip the
+        // remaining comparisons once one of them fails.
+        quote! {
+            let mask: u8 = 0xFF;
+            #(
+                let mask = ::constant_time_eq::constant_time_mask_and(
+                    mask,
+                    0u8.wrapping_sub(u8::from(::constant_time_eq::ConstantTimeEq::ct_eq(
+                        &self.#members,
+                        &other.#members,
+                    ))),
+                );
+            )*
+            mask != 0
+        }
+    };
+
+    for param in input.generics.type_params_mut() {
+        param
+            .bounds
+            .push(parse_quote!(::constant_time_eq::ConstantTimeEq));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::constant_time_eq::ConstantTimeEq for #ident #ty_generics #where_clause {
+            fn ct_eq(&self, other: &Self) -> bool {
+                #body
+            }
+        }
+    })
+}
+
+/// Checks whether a field is marked with `#[ct_eq(skip)]`.
+fn is_skipped(field: &Field) -> syn::Result<bool> {
+    let mut skip = false;
+    for attr in &field.attrs {
+        if attr.path().is_ident("ct_eq") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported ct_eq attribute, expected `skip`"))
+                }
+            })?;
+        }
+    }
+    Ok(skip)
+}
@@ -0,0 +1,64 @@
+use constant_time_eq::ConstantTimeEq;
+
+#[derive(ConstantTimeEq)]
+struct Named {
+    key: [u8; 32],
+    nonce: Vec<u8>,
+    #[ct_eq(skip)]
+    counter: u64,
+}
+
+#[derive(ConstantTimeEq)]
+struct Tuple([u8; 16], #[ct_eq(skip)] u32, Box<[u8]>);
+
+#[derive(ConstantTimeEq)]
+struct Unit;
+
+#[derive(ConstantTimeEq)]
+struct Generic<T> {
+    inner: T,
+}
+
+#[test]
+fn test_derive_named() {
+    let a = Named {
+        key: [1; 32],
+        nonce: vec![2; 12],
+        counter: 3,
+    };
+    let mut b = Named {
+        key: [1; 32],
+        nonce: vec![2; 12],
+        counter: 4,
+    };
+    assert_eq!(a.counter + 1, b.counter);
+    assert!(a.ct_eq(&b));
+
+    b.key[31] ^= 1;
+    assert!(a.ct_ne(&b));
+    b.key[31] ^= 1;
+
+    b.nonce.push(0);
+    assert!(a.ct_ne(&b));
+}
+
+#[test]
+fn test_derive_tuple() {
+    let a = Tuple([1; 16], 2, Box::new([3; 4]));
+    let b = Tuple([1; 16], 5, Box::new([3; 4]));
+    assert_eq!(a.1 + 3, b.1);
+    assert!(a.ct_eq(&b));
+    assert!(a.ct_ne(&Tuple([1; 16], 2, Box::new([4; 4]))));
+}
+
+#[test]
+fn test_derive_unit() {
+    assert!(Unit.ct_eq(&Unit));
+}
+
+#[test]
+fn test_derive_generic() {
+    let a = Generic { inner: [7u8; 8] };
+    assert!(a.ct_eq(&Generic { inner: [7; 8] }));
+    assert!(a.ct_ne(&Generic { inner: [8; 8] }));
+}
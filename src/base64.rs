@@ -0,0 +1,24 @@
+//! Alphabets for comparing against Base64-encoded text.
+
+/// The Base64 alphabet used by [`constant_time_eq_base64`](crate::constant_time_eq_base64).
+///
+/// Both alphabets share the letters and digits, and differ only in the last two characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Base64Alphabet {
+    /// The standard alphabet from RFC 4648, section 4, using `+` and `/`.
+    Standard,
+    /// The URL and filename safe alphabet from RFC 4648, section 5, using `-` and `_`.
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    /// Returns the characters for the values 62 and 63.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) const fn symbols(self) -> (u8, u8) {
+        match self {
+            Base64Alphabet::Standard => (b'+', b'/'),
+            Base64Alphabet::UrlSafe => (b'-', b'_'),
+        }
+    }
+}
@@ -295,11 +295,79 @@ where
     }
 }
 
+/// The DIT state saved by `enter_dit`, to be restored by `leave_dit`.
+#[derive(Clone, Copy)]
+pub(crate) struct SavedDit(Option<u64>);
+
+/// Enables DIT when `FEAT_DIT` and `FEAT_SB` were detected, returning the previous state.
+///
+/// # Safety
+///
+/// `FEAT_DIT` and `FEAT_SB` must have been detected.
+#[inline]
+#[target_feature(enable = "dit,sb")]
+unsafe fn enter_feat_dit_sb() -> u64 {
+    // SAFETY: called only when `FEAT_DIT` is implemented
+    let dit = unsafe { rsr64_dit() };
+
+    // SAFETY: called only when `FEAT_DIT` is implemented
+    unsafe { enable_dit() };
+
+    // SAFETY: called only when `FEAT_SB` is implemented
+    unsafe { speculation_barrier() };
+
+    dit
+}
+
+/// Enables DIT when `FEAT_DIT` was detected but not `FEAT_SB`, returning the previous state.
+///
+/// # Safety
+///
+/// `FEAT_DIT` must have been detected.
+#[inline]
+#[target_feature(enable = "dit")]
+unsafe fn enter_feat_dit() -> u64 {
+    // SAFETY: called only when `FEAT_DIT` is implemented
+    let dit = unsafe { rsr64_dit() };
+
+    // SAFETY: called only when `FEAT_DIT` is implemented
+    unsafe { enable_dit() };
+
+    synchronization_barrier();
+
+    dit
+}
+
+/// Enables the hardware DIT feature when possible, returning the previous state.
+#[inline]
+pub(crate) fn enter_dit() -> SavedDit {
+    match get_aarch64_dit_sb_features() {
+        Features::DitSb => {
+            // SAFETY: both `FEAT_DIT` and `FEAT_SB` were detected
+            SavedDit(Some(unsafe { enter_feat_dit_sb() }))
+        }
+        Features::DitOnly => {
+            // SAFETY: `FEAT_DIT` was detected
+            SavedDit(Some(unsafe { enter_feat_dit() }))
+        }
+        Features::Neither => SavedDit(None),
+    }
+}
+
+/// Restores the hardware DIT feature to the state saved by `enter_dit`.
+#[inline]
+pub(crate) fn leave_dit(saved: SavedDit) {
+    if let SavedDit(Some(dit)) = saved {
+        // SAFETY: `enter_dit` saves a value only when `FEAT_DIT` is implemented
+        unsafe { wsr64_dit(dit) };
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate std;
 
-    use super::{rsr64_dit, with_dit};
+    use super::{enter_dit, leave_dit, rsr64_dit, with_dit};
     use std::arch::is_aarch64_feature_detected;
 
     #[test]
@@ -313,4 +381,22 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn dit_is_restored_after_leave_dit() {
+        if is_aarch64_feature_detected!("dit") {
+            // SAFETY: `FEAT_DIT` was detected
+            unsafe {
+                let saved = rsr64_dit();
+                let outer = enter_dit();
+                assert_ne!(rsr64_dit(), 0);
+                let inner = enter_dit();
+                assert_ne!(rsr64_dit(), 0);
+                leave_dit(inner);
+                assert_ne!(rsr64_dit(), 0);
+                leave_dit(outer);
+                assert_eq!(rsr64_dit(), saved);
+            }
+        }
+    }
 }
@@ -1,4 +1,4 @@
-//! Generic implementation of `constant_time_eq` and `constant_time_eq_n`.
+//! Generic implementation of `constant_time_eq` and the other comparisons.
 //!
 //! This implementation does SIMD in general-purpose registers instead of vector registers, and
 //! uses inline assembly only to hide the dependencies and comparisons from the optimizer, to
@@ -9,10 +9,13 @@
 //! architecture-specific vector implementations. This is simpler and often faster than trying to
 //! load a partial vector register.
 
-use core::mem::size_of;
-use core::ops::BitXor;
+use core::cmp::Ordering;
+use core::mem::{size_of, take};
+use core::ops::{BitAnd, BitXor};
 use core::ptr::read_unaligned;
 
+use crate::Base64Alphabet;
+use crate::unsigned::{self, Unsigned};
 use crate::with_dit;
 
 /// The natural word type for this architecture. All bit patterns must be valid for this type.
@@ -68,7 +71,7 @@ pub(crate) type Word = u8;
 ))]
 #[must_use]
 #[inline(always)]
-fn optimizer_hide(mut value: Word) -> Word {
+pub(crate) fn optimizer_hide(mut value: Word) -> Word {
     // SAFETY: the input value is passed unchanged to the output, the inline assembly does nothing.
     unsafe {
         core::arch::asm!("/* {0} */", inlateout(reg) value, options(pure, nomem, preserves_flags, nostack));
@@ -95,7 +98,7 @@ fn optimizer_hide(mut value: Word) -> Word {
 ))]
 #[must_use]
 #[inline(never)]
-fn optimizer_hide(value: Word) -> Word {
+pub(crate) fn optimizer_hide(value: Word) -> Word {
     // The current implementation of black_box in the main codegen backends is similar to
     // {
     //     let result = value;
@@ -110,6 +113,38 @@ fn optimizer_hide(value: Word) -> Word {
     core::hint::black_box(value)
 }
 
+/// Integer types which can be hidden from the optimizer.
+pub(crate) trait Hide: Copy {
+    /// Hides a value from the optimizer.
+    #[must_use]
+    fn hide(self) -> Self;
+}
+
+macro_rules! impl_hide {
+    ($($t:ty),*) => {
+        $(
+            impl Hide for $t {
+                #[inline(always)]
+                fn hide(self) -> Self {
+                    if size_of::<Self>() <= size_of::<Word>() {
+                        optimizer_hide(self as Word) as Self
+                    } else {
+                        // Too large for a single register, hide each word separately.
+                        let mut bytes = self.to_ne_bytes();
+                        for chunk in bytes.chunks_exact_mut(size_of::<Word>()) {
+                            let word = Word::from_ne_bytes(chunk.try_into().unwrap());
+                            chunk.copy_from_slice(&optimizer_hide(word).to_ne_bytes());
+                        }
+                        Self::from_ne_bytes(bytes)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_hide!(u8, u16, u32, u64, u128, usize);
+
 /// Equivalent to `read_unaligned` for byte slices.
 ///
 /// # Safety
@@ -126,11 +161,13 @@ unsafe fn read_unaligned_from_slice<T>(src: &[u8]) -> T {
 }
 
 /// Generic implementation of `constant_time_eq` and `constant_time_eq_n`.
+///
+/// Returns zero if both inputs are equal, non-zero otherwise.
 #[must_use]
 #[inline(always)]
-pub(crate) fn constant_time_eq_impl(mut a: &[u8], mut b: &[u8], mut tmp: Word) -> bool {
+pub(crate) fn constant_time_ne_impl(mut a: &[u8], mut b: &[u8], mut tmp: Word) -> Word {
     if a.len() != b.len() {
-        return false;
+        return !0;
     }
 
     // This statement does nothing, because a.len() == b.len() here,
@@ -139,7 +176,7 @@ pub(crate) fn constant_time_eq_impl(mut a: &[u8], mut b: &[u8], mut tmp: Word) -
 
     // Early exit for the common case when called by the SIMD code.
     if a.is_empty() {
-        return tmp == 0;
+        return tmp;
     }
 
     /// Reads and compares a single word from the input, adjusting the slices.
@@ -201,43 +238,1352 @@ pub(crate) fn constant_time_eq_impl(mut a: &[u8], mut b: &[u8], mut tmp: Word) -
         tmp = optimizer_hide(tmp | cmp);
     }
 
-    tmp == 0
+    tmp
 }
 
-/// Compares two equal-sized byte strings in constant time.
+/// Generic implementation of `constant_time_eq_elements` and `constant_time_eq_elements_n`.
 ///
-/// # Examples
+/// Returns zero if both inputs are equal, non-zero otherwise.
+#[must_use]
+#[inline(always)]
+pub(crate) fn constant_time_ne_elements_impl<T: Unsigned>(
+    a: &[T],
+    mut b: &[T],
+    mut tmp: Word,
+) -> Word {
+    if a.len() != b.len() {
+        return !0;
+    }
+
+    // This statement does nothing, because a.len() == b.len() here,
+    // but it makes the optimizer elide some useless bounds checks.
+    b = &b[..a.len()];
+
+    for (&a, &b) in a.iter().zip(b) {
+        tmp = optimizer_hide(tmp | optimizer_hide(unsigned::ne_word(a, b)));
+    }
+
+    tmp
+}
+
+/// Generic implementation of `constant_time_eq_masked` and `constant_time_eq_n_masked`.
 ///
-/// ```
-/// use constant_time_eq::constant_time_eq;
+/// Returns zero if both inputs are equal in all bits set in the mask, non-zero otherwise.
+#[must_use]
+#[inline(always)]
+pub(crate) fn constant_time_ne_masked_impl(
+    mut a: &[u8],
+    mut b: &[u8],
+    mut mask: &[u8],
+    mut tmp: Word,
+) -> Word {
+    if a.len() != b.len() || a.len() != mask.len() {
+        return !0;
+    }
+
+    // These statements do nothing, because a.len() == b.len() == mask.len() here,
+    // but they make the optimizer elide some useless bounds checks.
+    b = &b[..a.len()];
+    mask = &mask[..a.len()];
+
+    // Early exit for the common case when called by the SIMD code.
+    if a.is_empty() {
+        return tmp;
+    }
+
+    /// Reads and compares a single word from the input, adjusting the slices.
+    /// Returns zero if both words are equal in all bits set in the mask, non-zero otherwise.
+    ///
+    /// # Safety
+    ///
+    /// All bit patterns must be valid for type T.
+    #[must_use]
+    #[inline(always)]
+    unsafe fn cmp_step<T: BitXor<Output = T> + BitAnd<Output = T>>(
+        a: &mut &[u8],
+        b: &mut &[u8],
+        mask: &mut &[u8],
+    ) -> T {
+        // SAFETY: all bit patterns are valid for type T
+        let tmpa = unsafe { read_unaligned_from_slice::<T>(&a[..size_of::<T>()]) };
+        // SAFETY: all bit patterns are valid for type T
+        let tmpb = unsafe { read_unaligned_from_slice::<T>(&b[..size_of::<T>()]) };
+        // SAFETY: all bit patterns are valid for type T
+        let tmpm = unsafe { read_unaligned_from_slice::<T>(&mask[..size_of::<T>()]) };
+
+        *a = &a[size_of::<T>()..];
+        *b = &b[size_of::<T>()..];
+        *mask = &mask[size_of::<T>()..];
+
+        (tmpa ^ tmpb) & tmpm
+    }
+
+    // See constant_time_ne_impl for the reasoning behind these blocks.
+    while a.len() >= size_of::<Word>() {
+        // SAFETY: all bit patterns are valid for Word
+        let cmp = optimizer_hide(unsafe { cmp_step::<Word>(&mut a, &mut b, &mut mask) });
+        tmp = optimizer_hide(tmp | cmp);
+    }
+    while a.len() >= size_of::<u128>() {
+        // SAFETY: all bit patterns are valid for u128
+        let cmp = optimizer_hide(unsafe { cmp_step::<u128>(&mut a, &mut b, &mut mask) } as Word);
+        tmp = optimizer_hide(tmp | cmp);
+    }
+    if a.len() >= size_of::<u64>() {
+        // SAFETY: all bit patterns are valid for u64
+        let cmp = optimizer_hide(unsafe { cmp_step::<u64>(&mut a, &mut b, &mut mask) } as Word);
+        tmp = optimizer_hide(tmp | cmp);
+    }
+    if a.len() >= size_of::<u32>() {
+        // SAFETY: all bit patterns are valid for u32
+        let cmp = optimizer_hide(unsafe { cmp_step::<u32>(&mut a, &mut b, &mut mask) } as Word);
+        tmp = optimizer_hide(tmp | cmp);
+    }
+    if a.len() >= size_of::<u16>() {
+        // SAFETY: all bit patterns are valid for u16
+        let cmp = optimizer_hide(unsafe { cmp_step::<u16>(&mut a, &mut b, &mut mask) } as Word);
+        tmp = optimizer_hide(tmp | cmp);
+    }
+    if a.len() >= size_of::<u8>() {
+        // SAFETY: all bit patterns are valid for u8
+        let cmp = optimizer_hide(unsafe { cmp_step::<u8>(&mut a, &mut b, &mut mask) } as Word);
+        tmp = optimizer_hide(tmp | cmp);
+    }
+
+    tmp
+}
+
+/// Converts the ASCII uppercase letters in each byte of a word to lowercase, without branches.
+#[must_use]
+#[inline(always)]
+fn to_ascii_lowercase_word(value: Word) -> Word {
+    const ONES: Word = Word::MAX / 0xFF;
+
+    // Adding to the low seven bits of each byte cannot carry into the next byte, and the top
+    // bit of each sum tells whether the byte is at least 'A', or greater than 'Z'.
+    let low = value & (ONES * 0x7F);
+    let ge_a = low + ONES * (0x80 - b'A' as Word);
+    let gt_z = low + ONES * (0x80 - b'Z' as Word - 1);
+    let upper = ge_a & !gt_z & !value & (ONES * 0x80);
+
+    // Moves the top bit of each uppercase byte into the 0x20 bit.
+    value | (upper >> 2)
+}
+
+/// Generic implementation of `constant_time_eq_ignore_ascii_case`.
 ///
-/// assert!(constant_time_eq(b"foo", b"foo"));
-/// assert!(!constant_time_eq(b"foo", b"bar"));
-/// assert!(!constant_time_eq(b"bar", b"baz"));
-/// # assert!(constant_time_eq(b"", b""));
+/// Returns zero if both inputs are equal when ignoring ASCII case, non-zero otherwise.
+#[must_use]
+#[inline(always)]
+pub(crate) fn constant_time_ne_ignore_ascii_case_impl(
+    mut a: &[u8],
+    mut b: &[u8],
+    mut tmp: Word,
+) -> Word {
+    if a.len() != b.len() {
+        return !0;
+    }
+
+    // This statement does nothing, because a.len() == b.len() here,
+    // but it makes the optimizer elide some useless bounds checks.
+    b = &b[..a.len()];
+
+    while a.len() >= size_of::<Word>() {
+        // SAFETY: all bit patterns are valid for Word
+        let tmpa = unsafe { read_unaligned_from_slice::<Word>(&a[..size_of::<Word>()]) };
+        // SAFETY: all bit patterns are valid for Word
+        let tmpb = unsafe { read_unaligned_from_slice::<Word>(&b[..size_of::<Word>()]) };
+
+        a = &a[size_of::<Word>()..];
+        b = &b[size_of::<Word>()..];
+
+        let tmpa = to_ascii_lowercase_word(optimizer_hide(tmpa));
+        let tmpb = to_ascii_lowercase_word(optimizer_hide(tmpb));
+        tmp = optimizer_hide(tmp | optimizer_hide(tmpa ^ tmpb));
+    }
+
+    // The leftovers are compared one byte at a time.
+    for (&tmpa, &tmpb) in a.iter().zip(b) {
+        let tmpa = to_ascii_lowercase_word(optimizer_hide(tmpa.into()));
+        let tmpb = to_ascii_lowercase_word(optimizer_hide(tmpb.into()));
+        tmp = optimizer_hide(tmp | optimizer_hide(tmpa ^ tmpb));
+    }
+
+    tmp
+}
+
+/// Generic implementation of `constant_time_contains` and `constant_time_position`.
 ///
-/// // Not equal-sized, so won't take constant time.
-/// assert!(!constant_time_eq(b"foo", b""));
-/// assert!(!constant_time_eq(b"foo", b"quux"));
-/// ```
+/// The `ne` parameter must return zero if both inputs are equal, non-zero otherwise.
 #[must_use]
-pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
-    with_dit(|| constant_time_eq_impl(a, b, 0))
+#[inline(always)]
+pub(crate) fn constant_time_position_impl<F>(
+    needle: &[u8],
+    candidates: &[&[u8]],
+    ne: F,
+) -> (u8, usize)
+where
+    F: Fn(&[u8], &[u8]) -> Word,
+{
+    let mut found: Word = 0;
+    let mut index: usize = 0;
+
+    // Every candidate is compared, even after a match was found.
+    for (i, candidate) in candidates.iter().enumerate() {
+        let eq = optimizer_hide(nonzero_bit(ne(needle, candidate)) ^ 1);
+        let first = eq & (found ^ 1);
+        index = (index | (i & (first as usize).wrapping_neg())).hide();
+        found = optimizer_hide(found | eq);
+    }
+
+    ((found as u8).wrapping_neg(), index)
 }
 
-/// Compares two fixed-size byte strings in constant time.
+/// Converts the result of `constant_time_ne_impl` into an all-ones mask if it is zero, or an
+/// all-zeros mask otherwise.
+#[must_use]
+#[inline(always)]
+pub(crate) fn eq_mask_u8(tmp: Word) -> u8 {
+    (optimizer_hide(nonzero_bit(tmp) ^ 1) as u8).wrapping_neg()
+}
+
+/// Converts the result of `constant_time_ne_impl` into an all-ones mask if it is zero, or an
+/// all-zeros mask otherwise.
+#[must_use]
+#[inline(always)]
+pub(crate) fn eq_mask_u64(tmp: Word) -> u64 {
+    (optimizer_hide(nonzero_bit(tmp) ^ 1) as u64).wrapping_neg()
+}
+
+/// Generic implementation of `constant_time_is_zero` and `constant_time_is_zero_n`.
+#[must_use]
+#[inline(always)]
+pub(crate) fn constant_time_is_zero_impl(mut a: &[u8], mut tmp: Word) -> bool {
+    // Early exit for the common case when called by the SIMD code.
+    if a.is_empty() {
+        return tmp == 0;
+    }
+
+    /// Reads a single word from the input, adjusting the slice.
+    ///
+    /// # Safety
+    ///
+    /// All bit patterns must be valid for type T.
+    #[must_use]
+    #[inline(always)]
+    unsafe fn read_step<T>(a: &mut &[u8]) -> T {
+        // SAFETY: all bit patterns are valid for type T
+        let tmpa = unsafe { read_unaligned_from_slice::<T>(&a[..size_of::<T>()]) };
+
+        *a = &a[size_of::<T>()..];
+
+        tmpa
+    }
+
+    // See constant_time_ne_impl for the reasoning behind these blocks.
+    while a.len() >= size_of::<Word>() {
+        // SAFETY: all bit patterns are valid for Word
+        let cmp = optimizer_hide(unsafe { read_step::<Word>(&mut a) });
+        tmp = optimizer_hide(tmp | cmp);
+    }
+    while a.len() >= size_of::<u128>() {
+        // SAFETY: all bit patterns are valid for u128
+        let cmp = optimizer_hide(unsafe { read_step::<u128>(&mut a) } as Word);
+        tmp = optimizer_hide(tmp | cmp);
+    }
+    if a.len() >= size_of::<u64>() {
+        // SAFETY: all bit patterns are valid for u64
+        let cmp = optimizer_hide(unsafe { read_step::<u64>(&mut a) } as Word);
+        tmp = optimizer_hide(tmp | cmp);
+    }
+    if a.len() >= size_of::<u32>() {
+        // SAFETY: all bit patterns are valid for u32
+        let cmp = optimizer_hide(unsafe { read_step::<u32>(&mut a) } as Word);
+        tmp = optimizer_hide(tmp | cmp);
+    }
+    if a.len() >= size_of::<u16>() {
+        // SAFETY: all bit patterns are valid for u16
+        let cmp = optimizer_hide(unsafe { read_step::<u16>(&mut a) } as Word);
+        tmp = optimizer_hide(tmp | cmp);
+    }
+    if a.len() >= size_of::<u8>() {
+        // SAFETY: all bit patterns are valid for u8
+        let cmp = optimizer_hide(unsafe { read_step::<u8>(&mut a) } as Word);
+        tmp = optimizer_hide(tmp | cmp);
+    }
+
+    tmp == 0
+}
+
+/// Returns one if `a < b`, zero otherwise, using the borrow of `a - b`.
+#[must_use]
+#[inline(always)]
+fn lt_bit_usize(a: usize, b: usize) -> Word {
+    (((!a & b) | (!(a ^ b) & a.wrapping_sub(b))) >> (usize::BITS - 1)) as Word
+}
+
+/// Returns an all-ones mask if both indexes are equal, or an all-zeros mask otherwise.
+#[must_use]
+#[inline(always)]
+pub(crate) fn index_eq_mask(a: usize, b: usize) -> u8 {
+    let diff = (a ^ b).hide();
+    eq_mask_u8(((diff | diff.wrapping_neg()) >> (usize::BITS - 1)) as Word)
+}
+
+/// Reads the byte at `index`, or zero if `index` is past the end of the slice, without branching
+/// on the length of the slice.
 ///
-/// # Examples
+/// The address read still depends on the length of the slice.
+#[must_use]
+#[inline(always)]
+fn read_padded(src: &[u8], index: usize) -> Word {
+    let in_bounds = optimizer_hide(lt_bit_usize(index, src.len()));
+    let nonempty = optimizer_hide(lt_bit_usize(0, src.len()));
+
+    // Reads past the end are redirected to the first byte and masked out afterwards. An empty
+    // slice has no first byte, so its reads go to a zero byte instead. Both are chosen with masks
+    // instead of bounds checks, so that every length takes the same path.
+    let sources: [&[u8]; 2] = [&[0], src];
+    let src = sources[(nonempty & 1) as usize];
+    let index = index & usize_mask(in_bounds);
+
+    // SAFETY: index is either within the slice, or zero and the slice is not empty
+    Word::from(unsafe { *src.get_unchecked(index) }) & in_bounds.wrapping_neg()
+}
+
+/// Generic implementation of `constant_time_eq_padded`.
+#[must_use]
+#[inline(always)]
+pub(crate) fn constant_time_eq_padded_impl(a: &[u8], b: &[u8], max_len: usize) -> bool {
+    // Inputs longer than max_len, or with different lengths, are never equal.
+    let mut tmp = optimizer_hide(
+        lt_bit_usize(max_len, a.len())
+            | lt_bit_usize(max_len, b.len())
+            | lt_bit_usize(a.len(), b.len())
+            | lt_bit_usize(b.len(), a.len()),
+    );
+
+    // The loop count depends only on max_len; the bytes past the end of each input are zero.
+    for i in 0..max_len {
+        let cmp = optimizer_hide(read_padded(a, i) ^ read_padded(b, i));
+        tmp = optimizer_hide(tmp | cmp);
+    }
+
+    tmp == 0
+}
+
+/// Converts a non-zero value to one, and zero to zero.
+#[must_use]
+#[inline(always)]
+fn nonzero_bit(value: Word) -> Word {
+    (value | value.wrapping_neg()) >> (Word::BITS - 1)
+}
+
+/// Returns one if `a < b`, zero otherwise, using the borrow of `a - b`.
+#[must_use]
+#[inline(always)]
+fn lt_bit(a: Word, b: Word) -> Word {
+    ((!a & b) | (!(a ^ b) & a.wrapping_sub(b))) >> (Word::BITS - 1)
+}
+
+/// Decodes a hexadecimal digit of either case without branches.
 ///
-/// ```
-/// use constant_time_eq::constant_time_eq_n;
+/// Returns the value of the digit, and one if the digit is invalid, zero otherwise.
+#[must_use]
+#[inline(always)]
+fn decode_hex_digit(c: u8) -> (Word, Word) {
+    let c = optimizer_hide(Word::from(c));
+    let digit = c.wrapping_sub(Word::from(b'0'));
+    let letter = (c | 0x20).wrapping_sub(Word::from(b'a'));
+    let is_digit = lt_bit(digit, 10);
+    let is_letter = lt_bit(letter, 6);
+    let value =
+        (digit & is_digit.wrapping_neg()) | (letter.wrapping_add(10) & is_letter.wrapping_neg());
+    (value, (is_digit | is_letter) ^ 1)
+}
+
+/// Generic implementation of `constant_time_eq_hex`.
 ///
-/// assert!(constant_time_eq_n(&[3; 20], &[3; 20]));
-/// assert!(!constant_time_eq_n(&[3; 20], &[7; 20]));
-/// ```
+/// Returns zero if the hex string decodes to the raw bytes, non-zero otherwise.
 #[must_use]
-pub fn constant_time_eq_n<const N: usize>(a: &[u8; N], b: &[u8; N]) -> bool {
-    with_dit(|| constant_time_eq_impl(&a[..], &b[..], 0))
+#[inline(always)]
+pub(crate) fn constant_time_ne_hex_impl(raw: &[u8], hex: &[u8]) -> Word {
+    // Cannot overflow, since the length of a slice is at most isize::MAX.
+    if hex.len() != raw.len() * 2 {
+        return !0;
+    }
+
+    let mut tmp = 0;
+    for (&byte, pair) in raw.iter().zip(hex.chunks_exact(2)) {
+        let (hi, hi_invalid) = decode_hex_digit(pair[0]);
+        let (lo, lo_invalid) = decode_hex_digit(pair[1]);
+        let cmp = optimizer_hide(((hi << 4) | lo) ^ Word::from(byte));
+        tmp = optimizer_hide(tmp | cmp | hi_invalid | lo_invalid);
+    }
+
+    tmp
+}
+
+/// Decodes a Base64 character without branches.
+///
+/// Returns the value of the character, and one if the character is invalid, zero otherwise.
+#[must_use]
+#[inline(always)]
+fn decode_base64_char(c: u8, alphabet: Base64Alphabet) -> (Word, Word) {
+    let (c62, c63) = alphabet.symbols();
+    let c = optimizer_hide(Word::from(c));
+    let upper = c.wrapping_sub(Word::from(b'A'));
+    let lower = c.wrapping_sub(Word::from(b'a'));
+    let digit = c.wrapping_sub(Word::from(b'0'));
+    let is_upper = lt_bit(upper, 26);
+    let is_lower = lt_bit(lower, 26);
+    let is_digit = lt_bit(digit, 10);
+    let is_62 = nonzero_bit(c ^ Word::from(c62)) ^ 1;
+    let is_63 = nonzero_bit(c ^ Word::from(c63)) ^ 1;
+    let value = (upper & is_upper.wrapping_neg())
+        | (lower.wrapping_add(26) & is_lower.wrapping_neg())
+        | (digit.wrapping_add(52) & is_digit.wrapping_neg())
+        | (62 & is_62.wrapping_neg())
+        | (63 & is_63.wrapping_neg());
+    (value, (is_upper | is_lower | is_digit | is_62 | is_63) ^ 1)
+}
+
+/// Generic implementation of `constant_time_eq_base64`.
+///
+/// Returns zero if the text decodes to the raw bytes, non-zero otherwise.
+#[must_use]
+#[inline(always)]
+pub(crate) fn constant_time_ne_base64_impl(
+    raw: &[u8],
+    encoded: &[u8],
+    alphabet: Base64Alphabet,
+) -> Word {
+    let unpadded_len = raw.len() / 3 * 4 + [0, 2, 3][raw.len() % 3];
+    let padded_len = raw.len().div_ceil(3) * 4;
+    if encoded.len() != unpadded_len && encoded.len() != padded_len {
+        return !0;
+    }
+
+    let (chars, padding) = encoded.split_at(unpadded_len);
+
+    let mut tmp = 0;
+    for (bytes, group) in raw.chunks(3).zip(chars.chunks(4)) {
+        let mut values = [0; 4];
+        for (value, &c) in values.iter_mut().zip(group) {
+            let invalid;
+            (*value, invalid) = decode_base64_char(c, alphabet);
+            tmp = optimizer_hide(tmp | invalid);
+        }
+
+        let [v0, v1, v2, v3] = values;
+        let decoded = [
+            (v0 << 2) | (v1 >> 4),
+            ((v1 & 0xF) << 4) | (v2 >> 2),
+            ((v2 & 0x3) << 6) | v3,
+        ];
+        for (&byte, &decoded) in bytes.iter().zip(&decoded) {
+            let cmp = optimizer_hide(Word::from(byte) ^ decoded);
+            tmp = optimizer_hide(tmp | cmp);
+        }
+
+        // The unused bits of the last character of a partial group must be zero.
+        let unused = match bytes.len() {
+            1 => v1 & 0xF,
+            2 => v2 & 0x3,
+            _ => 0,
+        };
+        tmp = optimizer_hide(tmp | unused);
+    }
+
+    for &c in padding {
+        let cmp = optimizer_hide(Word::from(c) ^ Word::from(b'='));
+        tmp = optimizer_hide(tmp | cmp);
+    }
+
+    tmp
+}
+
+/// Converts a condition to a byte mask, all ones if true and all zeros if false.
+#[must_use]
+#[inline(always)]
+pub(crate) fn bool_mask(cond: bool) -> u8 {
+    optimizer_hide(Word::from(cond)).wrapping_neg() as u8
+}
+
+/// Generic implementation of `ct_select` and `ct_copy_if`.
+///
+/// Replaces each bit of `dst` with the corresponding bit of `src` where the mask bit is set.
+#[inline(always)]
+pub(crate) fn ct_select_in_place_impl(mask: u8, mut src: &[u8], mut dst: &mut [u8]) {
+    assert_eq!(src.len(), dst.len());
+
+    let mask = Word::from(mask) * (Word::MAX / 0xFF);
+
+    while dst.len() >= size_of::<Word>() {
+        let (head, tail) = take(&mut dst).split_at_mut(size_of::<Word>());
+
+        // SAFETY: all bit patterns are valid for Word
+        let tmpa = unsafe { read_unaligned_from_slice::<Word>(&src[..size_of::<Word>()]) };
+        // SAFETY: all bit patterns are valid for Word
+        let tmpb = unsafe { read_unaligned_from_slice::<Word>(head) };
+
+        let tmp = tmpb ^ (optimizer_hide(tmpa ^ tmpb) & optimizer_hide(mask));
+        head.copy_from_slice(&tmp.to_ne_bytes());
+
+        src = &src[size_of::<Word>()..];
+        dst = tail;
+    }
+
+    // The leftovers are selected one byte at a time.
+    for (dst, &src) in dst.iter_mut().zip(src) {
+        let (tmpa, tmpb) = (Word::from(src), Word::from(*dst));
+        *dst = (tmpb ^ (optimizer_hide(tmpa ^ tmpb) & optimizer_hide(mask))) as u8;
+    }
+}
+
+/// Generic implementation of `ct_swap`.
+///
+/// Exchanges each bit of `a` with the corresponding bit of `b` where the mask bit is set.
+#[inline(always)]
+pub(crate) fn ct_swap_impl(mask: u8, mut a: &mut [u8], mut b: &mut [u8]) {
+    assert_eq!(a.len(), b.len());
+
+    let mask = Word::from(mask) * (Word::MAX / 0xFF);
+
+    while a.len() >= size_of::<Word>() {
+        let (heada, taila) = take(&mut a).split_at_mut(size_of::<Word>());
+        let (headb, tailb) = take(&mut b).split_at_mut(size_of::<Word>());
+
+        // SAFETY: all bit patterns are valid for Word
+        let tmpa = unsafe { read_unaligned_from_slice::<Word>(heada) };
+        // SAFETY: all bit patterns are valid for Word
+        let tmpb = unsafe { read_unaligned_from_slice::<Word>(headb) };
+
+        let tmp = optimizer_hide(tmpa ^ tmpb) & optimizer_hide(mask);
+        heada.copy_from_slice(&(tmpa ^ tmp).to_ne_bytes());
+        headb.copy_from_slice(&(tmpb ^ tmp).to_ne_bytes());
+
+        a = taila;
+        b = tailb;
+    }
+
+    // The leftovers are swapped one byte at a time.
+    for (a, b) in a.iter_mut().zip(b) {
+        let (tmpa, tmpb) = (Word::from(*a), Word::from(*b));
+        let tmp = optimizer_hide(tmpa ^ tmpb) & optimizer_hide(mask);
+        *a = (tmpa ^ tmp) as u8;
+        *b = (tmpb ^ tmp) as u8;
+    }
+}
+
+/// Generic implementation of `ct_swap_elements`.
+///
+/// Exchanges each bit of `a` with the corresponding bit of `b` where the mask bit is set.
+#[inline(always)]
+pub(crate) fn ct_swap_elements_impl<T: Unsigned>(mask: u8, a: &mut [T], b: &mut [T]) {
+    assert_eq!(a.len(), b.len());
+
+    for (a, b) in a.iter_mut().zip(b) {
+        unsigned::swap_masked(mask, a, b);
+    }
+}
+
+/// Generic implementation of `constant_time_pkcs7_unpad`.
+///
+/// Returns zero if the padding is valid, non-zero otherwise, and the length of the padding.
+#[must_use]
+#[inline(always)]
+pub(crate) fn constant_time_pkcs7_check_impl(block: &[u8], block_size: usize) -> (Word, Word) {
+    let pad = optimizer_hide(Word::from(block[block.len() - 1]));
+
+    // The padding length must be between 1 and the block size.
+    let mut tmp = optimizer_hide((nonzero_bit(pad) ^ 1) | lt_bit(block_size as Word, pad));
+
+    // All bytes of the last block are examined, whatever the padding length.
+    for (i, &byte) in block.iter().rev().take(block_size).enumerate() {
+        let in_pad = lt_bit(i as Word, pad);
+        let cmp = optimizer_hide((Word::from(byte) ^ pad) & in_pad.wrapping_neg());
+        tmp = optimizer_hide(tmp | cmp);
+    }
+
+    (tmp, pad)
+}
+
+/// Converts one to an all-ones mask, and zero to an all-zeros mask.
+#[must_use]
+#[inline(always)]
+fn usize_mask(bit: Word) -> usize {
+    (optimizer_hide(bit) as usize).wrapping_neg()
+}
+
+/// Generic implementation of `constant_time_pkcs1v15_unpad`.
+///
+/// Returns zero if the encoding is valid, non-zero otherwise, and the index of the separator.
+#[must_use]
+#[inline(always)]
+pub(crate) fn constant_time_pkcs1v15_check_impl(em: &[u8]) -> (Word, usize) {
+    // The encoding starts with 0x00 0x02.
+    let mut tmp = optimizer_hide(Word::from(em[0]) | (Word::from(em[1]) ^ 2));
+
+    // Finds the first zero byte after the header, examining all bytes.
+    let mut found = 0;
+    let mut sep = 0;
+    for (i, &byte) in em.iter().enumerate().skip(2) {
+        let is_zero = nonzero_bit(optimizer_hide(Word::from(byte))) ^ 1;
+        let first = is_zero & (found ^ 1);
+        sep = (sep | (i & usize_mask(first))).hide();
+        found = optimizer_hide(found | is_zero);
+    }
+
+    // The separator must exist, and the padding before it must have at least eight bytes.
+    tmp = optimizer_hide(tmp | (found ^ 1) | lt_bit_usize(sep, 10));
+
+    (tmp, sep)
+}
+
+/// Generic implementation of `constant_time_oaep_unpad`.
+///
+/// Returns zero if the encoding is valid, non-zero otherwise, and the index of the separator.
+#[must_use]
+#[inline(always)]
+pub(crate) fn constant_time_oaep_check_impl(em: &[u8], label_hash: &[u8]) -> (Word, usize) {
+    let db_start = 1 + 2 * label_hash.len();
+
+    // The encoding starts with 0x00, and the data block starts with the label hash.
+    let tmp = optimizer_hide(Word::from(em[0]));
+    let mut tmp = constant_time_ne_impl(&em[1 + label_hash.len()..db_start], label_hash, tmp);
+
+    // Finds the first non-zero byte after the label hash, which must be 0x01, examining all bytes.
+    let mut looking = 1;
+    let mut sep = 0;
+    for (i, &byte) in em.iter().enumerate().skip(db_start) {
+        let byte = optimizer_hide(Word::from(byte));
+        let nonzero = nonzero_bit(byte);
+        let first = looking & nonzero;
+        tmp = optimizer_hide(tmp | ((byte ^ 1) & first.wrapping_neg()));
+        sep = (sep | (i & usize_mask(first))).hide();
+        looking = optimizer_hide(looking & (nonzero ^ 1));
+    }
+
+    // The separator must exist.
+    tmp = optimizer_hide(tmp | looking);
+
+    (tmp, sep)
+}
+
+/// Generic implementation of `constant_time_lt_le_bytes` and `constant_time_lt_be_bytes`.
+///
+/// Takes the bytes from the least significant to the most significant, and returns one if `a < b`,
+/// zero otherwise, using the borrow of `a - b`.
+#[must_use]
+#[inline(always)]
+pub(crate) fn constant_time_lt_bytes_impl<'a, I>(bytes: I) -> Word
+where
+    I: Iterator<Item = (&'a u8, &'a u8)>,
+{
+    let mut borrow = 0;
+    for (&a, &b) in bytes {
+        let a = optimizer_hide(Word::from(a));
+        let b = optimizer_hide(Word::from(b));
+        let diff = optimizer_hide(a.wrapping_sub(b).wrapping_sub(borrow));
+
+        // The borrow out of the top bit of each byte, as in lt_bit.
+        borrow = optimizer_hide((((!a & b) | (!(a ^ b) & diff)) >> 7) & 1);
+    }
+    borrow
+}
+
+/// Accumulator for a lexicographic comparison.
+///
+/// Once the first difference is found, exactly one of the fields becomes one, and neither field
+/// changes afterwards.
+#[derive(Clone, Copy)]
+pub(crate) struct CmpState {
+    lt: Word,
+    gt: Word,
+}
+
+impl CmpState {
+    #[must_use]
+    #[inline(always)]
+    pub(crate) const fn new() -> Self {
+        Self { lt: 0, gt: 0 }
+    }
+
+    /// Adds the comparison of the next block of bytes.
+    ///
+    /// Each bit set in `lt` or `gt` marks a byte which is less than or greater than the
+    /// corresponding byte from the other side; the lowest bit set in either must correspond
+    /// to the first byte which differs.
+    #[inline(always)]
+    pub(crate) fn update(&mut self, lt: Word, gt: Word) {
+        let diff = optimizer_hide(lt | gt);
+        let first = diff & diff.wrapping_neg();
+        let lt = nonzero_bit(first & lt);
+        let gt = nonzero_bit(first & gt);
+
+        let undecided = (self.lt | self.gt) ^ 1;
+        self.lt = optimizer_hide(self.lt | (lt & undecided));
+        self.gt = optimizer_hide(self.gt | (gt & undecided));
+    }
+
+    /// Adds the comparison of the lengths, and returns the final result.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) fn finish(mut self, a_len: usize, b_len: usize) -> Ordering {
+        // The lengths are not secret, only the result of the comparison so far.
+        self.update(Word::from(a_len < b_len), Word::from(a_len > b_len));
+        match (self.lt, self.gt) {
+            (0, 0) => Ordering::Equal,
+            (0, _) => Ordering::Greater,
+            _ => Ordering::Less,
+        }
+    }
+}
+
+/// Generic implementation of `constant_time_cmp` and `constant_time_cmp_n`.
+///
+/// Both inputs must have the same length.
+#[must_use]
+#[inline(always)]
+pub(crate) fn constant_time_cmp_impl(mut a: &[u8], mut b: &[u8], mut state: CmpState) -> CmpState {
+    // This statement does nothing, because a.len() == b.len() here,
+    // but it makes the optimizer elide some useless bounds checks.
+    b = &b[..a.len()];
+
+    // Reading the words as big-endian makes the integer order equal to the lexicographic order.
+    while a.len() >= size_of::<Word>() {
+        let tmpa = Word::from_be_bytes(a[..size_of::<Word>()].try_into().unwrap());
+        let tmpb = Word::from_be_bytes(b[..size_of::<Word>()].try_into().unwrap());
+
+        a = &a[size_of::<Word>()..];
+        b = &b[size_of::<Word>()..];
+
+        let tmpa = optimizer_hide(tmpa);
+        let tmpb = optimizer_hide(tmpb);
+        state.update(lt_bit(tmpa, tmpb), lt_bit(tmpb, tmpa));
+    }
+
+    // The leftovers are compared one byte at a time.
+    for (&tmpa, &tmpb) in a.iter().zip(b) {
+        let tmpa = optimizer_hide(tmpa.into());
+        let tmpb = optimizer_hide(tmpb.into());
+        state.update(lt_bit(tmpa, tmpb), lt_bit(tmpb, tmpa));
+    }
+
+    state
+}
+
+/// Compares two equal-sized byte strings in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq;
+///
+/// assert!(constant_time_eq(b"foo", b"foo"));
+/// assert!(!constant_time_eq(b"foo", b"bar"));
+/// assert!(!constant_time_eq(b"bar", b"baz"));
+/// # assert!(constant_time_eq(b"", b""));
+///
+/// // Not equal-sized, so won't take constant time.
+/// assert!(!constant_time_eq(b"foo", b""));
+/// assert!(!constant_time_eq(b"foo", b"quux"));
+/// ```
+#[must_use]
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    with_dit(|| constant_time_ne_impl(a, b, 0) == 0)
+}
+
+/// Compares two fixed-size byte strings in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_n;
+///
+/// assert!(constant_time_eq_n(&[3; 20], &[3; 20]));
+/// assert!(!constant_time_eq_n(&[3; 20], &[7; 20]));
+/// ```
+#[must_use]
+pub fn constant_time_eq_n<const N: usize>(a: &[u8; N], b: &[u8; N]) -> bool {
+    with_dit(|| constant_time_ne_impl(&a[..], &b[..], 0) == 0)
+}
+
+/// Compares two byte strings in constant time, returning zero if they are equal.
+#[must_use]
+#[allow(dead_code)] // Used only when there is no SIMD implementation for this architecture.
+pub(crate) fn constant_time_ne(a: &[u8], b: &[u8]) -> Word {
+    with_dit(|| constant_time_ne_impl(a, b, 0))
+}
+
+/// Compares many pairs of byte strings in constant time, entering DIT mode only once.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_batch;
+///
+/// let mut out = [false; 2];
+/// constant_time_eq_batch(&[(b"foo", b"foo"), (b"foo", b"bar")], &mut out);
+/// assert_eq!(out, [true, false]);
+/// ```
+pub fn constant_time_eq_batch(pairs: &[(&[u8], &[u8])], out: &mut [bool]) {
+    with_dit(|| {
+        for (out, &(a, b)) in out.iter_mut().zip(pairs) {
+            *out = constant_time_ne_impl(a, b, 0) == 0;
+        }
+    })
+}
+
+/// Compares two equal-sized slices of unsigned integers in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_elements;
+///
+/// assert!(constant_time_eq_elements(&[1u32, 2, 3], &[1, 2, 3]));
+/// assert!(!constant_time_eq_elements(&[1u64, 2, 3], &[1, 2, 4]));
+/// ```
+#[must_use]
+pub fn constant_time_eq_elements<T: Unsigned>(a: &[T], b: &[T]) -> bool {
+    with_dit(|| constant_time_ne_elements_impl(a, b, 0) == 0)
+}
+
+/// Compares two fixed-size arrays of unsigned integers in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_elements_n;
+///
+/// assert!(constant_time_eq_elements_n(&[3u32; 16], &[3; 16]));
+/// assert!(!constant_time_eq_elements_n(&[3u128; 4], &[7; 4]));
+/// ```
+#[must_use]
+pub fn constant_time_eq_elements_n<T: Unsigned, const N: usize>(a: &[T; N], b: &[T; N]) -> bool {
+    with_dit(|| constant_time_ne_elements_impl(&a[..], &b[..], 0) == 0)
+}
+
+/// Compares two equal-sized byte strings in constant time, returning a mask.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_mask;
+///
+/// assert_eq!(constant_time_eq_mask(b"foo", b"foo"), 0xFF);
+/// assert_eq!(constant_time_eq_mask(b"foo", b"bar"), 0);
+/// ```
+#[must_use]
+pub fn constant_time_eq_mask(a: &[u8], b: &[u8]) -> u8 {
+    with_dit(|| eq_mask_u8(constant_time_ne_impl(a, b, 0)))
+}
+
+/// Compares two equal-sized byte strings in constant time, returning a 64-bit mask.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_mask_u64;
+///
+/// assert_eq!(constant_time_eq_mask_u64(b"foo", b"foo"), !0);
+/// assert_eq!(constant_time_eq_mask_u64(b"foo", b"bar"), 0);
+/// ```
+#[must_use]
+pub fn constant_time_eq_mask_u64(a: &[u8], b: &[u8]) -> u64 {
+    with_dit(|| eq_mask_u64(constant_time_ne_impl(a, b, 0)))
+}
+
+/// Compares two fixed-size byte strings in constant time, returning a mask.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_n_mask;
+///
+/// assert_eq!(constant_time_eq_n_mask(&[3; 20], &[3; 20]), 0xFF);
+/// assert_eq!(constant_time_eq_n_mask(&[3; 20], &[7; 20]), 0);
+/// ```
+#[must_use]
+pub fn constant_time_eq_n_mask<const N: usize>(a: &[u8; N], b: &[u8; N]) -> u8 {
+    with_dit(|| eq_mask_u8(constant_time_ne_impl(&a[..], &b[..], 0)))
+}
+
+/// Compares two fixed-size byte strings in constant time, returning a 64-bit mask.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_n_mask_u64;
+///
+/// assert_eq!(constant_time_eq_n_mask_u64(&[3; 20], &[3; 20]), !0);
+/// assert_eq!(constant_time_eq_n_mask_u64(&[3; 20], &[7; 20]), 0);
+/// ```
+#[must_use]
+pub fn constant_time_eq_n_mask_u64<const N: usize>(a: &[u8; N], b: &[u8; N]) -> u64 {
+    with_dit(|| eq_mask_u64(constant_time_ne_impl(&a[..], &b[..], 0)))
+}
+
+/// Compares two equal-sized byte strings in constant time, ignoring the bits not set in a mask.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_masked;
+///
+/// assert!(constant_time_eq_masked(b"\x12\x34", b"\x12\x35", b"\xFF\xF0"));
+/// assert!(!constant_time_eq_masked(b"\x12\x34", b"\x12\x35", b"\xFF\xFF"));
+/// ```
+#[must_use]
+pub fn constant_time_eq_masked(a: &[u8], b: &[u8], mask: &[u8]) -> bool {
+    with_dit(|| constant_time_ne_masked_impl(a, b, mask, 0) == 0)
+}
+
+/// Compares two fixed-size byte strings in constant time, ignoring the bits not set in a mask.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_n_masked;
+///
+/// assert!(constant_time_eq_n_masked(&[3; 20], &[7; 20], &[0xFB; 20]));
+/// assert!(!constant_time_eq_n_masked(&[3; 20], &[7; 20], &[0xFF; 20]));
+/// ```
+#[must_use]
+pub fn constant_time_eq_n_masked<const N: usize>(a: &[u8; N], b: &[u8; N], mask: &[u8; N]) -> bool {
+    with_dit(|| constant_time_ne_masked_impl(&a[..], &b[..], &mask[..], 0) == 0)
+}
+
+/// Compares two equal-sized byte strings in constant time, ignoring ASCII case.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_ignore_ascii_case;
+///
+/// assert!(constant_time_eq_ignore_ascii_case(b"Foo", b"fOO"));
+/// assert!(!constant_time_eq_ignore_ascii_case(b"foo", b"bar"));
+/// ```
+#[must_use]
+pub fn constant_time_eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    with_dit(|| constant_time_ne_ignore_ascii_case_impl(a, b, 0) == 0)
+}
+
+/// Checks whether a byte string is equal to any of the candidates in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_contains;
+///
+/// assert!(constant_time_contains(b"bar", &[b"foo", b"bar"]));
+/// assert!(!constant_time_contains(b"baz", &[b"foo", b"bar"]));
+/// ```
+#[must_use]
+pub fn constant_time_contains(needle: &[u8], candidates: &[&[u8]]) -> bool {
+    with_dit(|| {
+        constant_time_position_impl(needle, candidates, |a, b| constant_time_ne_impl(a, b, 0)).0
+            != 0
+    })
+}
+
+/// Finds the first candidate equal to a byte string in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_position;
+///
+/// assert_eq!(constant_time_position(b"bar", &[b"foo", b"bar"]), (0xFF, 1));
+/// assert_eq!(constant_time_position(b"baz", &[b"foo", b"bar"]), (0, 0));
+/// ```
+#[must_use]
+pub fn constant_time_position(needle: &[u8], candidates: &[&[u8]]) -> (u8, usize) {
+    with_dit(|| {
+        constant_time_position_impl(needle, candidates, |a, b| constant_time_ne_impl(a, b, 0))
+    })
+}
+
+/// Combines two masks with a bitwise AND, hiding them from the optimizer.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_mask_and;
+///
+/// assert_eq!(constant_time_mask_and(0xFF, 0xFF), 0xFF);
+/// assert_eq!(constant_time_mask_and(0xFF, 0), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn constant_time_mask_and(a: u8, b: u8) -> u8 {
+    (a.hide() & b.hide()).hide()
+}
+
+/// Combines two masks with a bitwise OR, hiding them from the optimizer.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_mask_or;
+///
+/// assert_eq!(constant_time_mask_or(0xFF, 0), 0xFF);
+/// assert_eq!(constant_time_mask_or(0, 0), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn constant_time_mask_or(a: u8, b: u8) -> u8 {
+    (a.hide() | b.hide()).hide()
+}
+
+/// Combines two 64-bit masks with a bitwise AND, hiding them from the optimizer.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_mask_and_u64;
+///
+/// assert_eq!(constant_time_mask_and_u64(!0, !0), !0);
+/// assert_eq!(constant_time_mask_and_u64(!0, 0), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn constant_time_mask_and_u64(a: u64, b: u64) -> u64 {
+    (a.hide() & b.hide()).hide()
+}
+
+/// Combines two 64-bit masks with a bitwise OR, hiding them from the optimizer.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_mask_or_u64;
+///
+/// assert_eq!(constant_time_mask_or_u64(!0, 0), !0);
+/// assert_eq!(constant_time_mask_or_u64(0, 0), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn constant_time_mask_or_u64(a: u64, b: u64) -> u64 {
+    (a.hide() | b.hide()).hide()
+}
+
+/// Checks whether a byte string is all zeros in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_is_zero;
+///
+/// assert!(constant_time_is_zero(&[0; 20]));
+/// assert!(!constant_time_is_zero(b"foo"));
+/// ```
+#[must_use]
+pub fn constant_time_is_zero(a: &[u8]) -> bool {
+    with_dit(|| constant_time_is_zero_impl(a, 0))
+}
+
+/// Checks whether a fixed-size byte string is all zeros in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_is_zero_n;
+///
+/// assert!(constant_time_is_zero_n(&[0; 20]));
+/// assert!(!constant_time_is_zero_n(&[3; 20]));
+/// ```
+#[must_use]
+pub fn constant_time_is_zero_n<const N: usize>(a: &[u8; N]) -> bool {
+    with_dit(|| constant_time_is_zero_impl(&a[..], 0))
+}
+
+/// Compares two byte strings of secret length in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_padded;
+///
+/// assert!(constant_time_eq_padded(b"foo", b"foo", 16));
+/// assert!(!constant_time_eq_padded(b"foo", b"bar", 16));
+/// assert!(!constant_time_eq_padded(b"foo", b"fo", 16));
+/// assert!(!constant_time_eq_padded(b"foo", b"foo", 2));
+/// ```
+#[must_use]
+pub fn constant_time_eq_padded(a: &[u8], b: &[u8], max_len: usize) -> bool {
+    with_dit(|| constant_time_eq_padded_impl(a, b, max_len))
+}
+
+/// Compares raw bytes with a hex-encoded string in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_hex;
+///
+/// assert!(constant_time_eq_hex(b"\xde\xad", b"dEAd"));
+/// assert!(!constant_time_eq_hex(b"\xde\xad", b"beef"));
+/// assert!(!constant_time_eq_hex(b"\xde\xad", b"dea"));
+/// assert!(!constant_time_eq_hex(b"\xde\xad", b"deag"));
+/// ```
+#[must_use]
+pub fn constant_time_eq_hex(raw: &[u8], hex: &[u8]) -> bool {
+    with_dit(|| constant_time_ne_hex_impl(raw, hex) == 0)
+}
+
+/// Compares raw bytes with Base64-encoded text in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::{Base64Alphabet, constant_time_eq_base64};
+///
+/// assert!(constant_time_eq_base64(b"foo", b"Zm9v", Base64Alphabet::Standard));
+/// assert!(constant_time_eq_base64(b"fo", b"Zm8=", Base64Alphabet::UrlSafe));
+/// assert!(constant_time_eq_base64(b"fo", b"Zm8", Base64Alphabet::UrlSafe));
+/// assert!(!constant_time_eq_base64(b"fo", b"Zm9=", Base64Alphabet::UrlSafe));
+/// ```
+#[must_use]
+pub fn constant_time_eq_base64(raw: &[u8], encoded: &[u8], alphabet: Base64Alphabet) -> bool {
+    with_dit(|| constant_time_ne_base64_impl(raw, encoded, alphabet) == 0)
+}
+
+/// Lexicographically compares two byte strings in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use constant_time_eq::constant_time_cmp;
+///
+/// assert_eq!(constant_time_cmp(b"foo", b"foo"), Ordering::Equal);
+/// assert_eq!(constant_time_cmp(b"bar", b"baz"), Ordering::Less);
+/// assert_eq!(constant_time_cmp(b"foo", b"bar"), Ordering::Greater);
+/// assert_eq!(constant_time_cmp(b"foo", b"fo"), Ordering::Greater);
+/// ```
+#[must_use]
+pub fn constant_time_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    with_dit(|| {
+        let len = a.len().min(b.len());
+        constant_time_cmp_impl(&a[..len], &b[..len], CmpState::new()).finish(a.len(), b.len())
+    })
+}
+
+/// Lexicographically compares two fixed-size byte strings in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use constant_time_eq::constant_time_cmp_n;
+///
+/// assert_eq!(constant_time_cmp_n(&[3; 20], &[3; 20]), Ordering::Equal);
+/// assert_eq!(constant_time_cmp_n(&[3; 20], &[7; 20]), Ordering::Less);
+/// ```
+#[must_use]
+pub fn constant_time_cmp_n<const N: usize>(a: &[u8; N], b: &[u8; N]) -> Ordering {
+    with_dit(|| constant_time_cmp_impl(&a[..], &b[..], CmpState::new()).finish(N, N))
+}
+
+/// Selects between two byte strings in constant time, bit by bit according to a mask.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_select;
+///
+/// let mut out = [0; 3];
+/// ct_select(0xFF, b"foo", b"bar", &mut out);
+/// assert_eq!(&out, b"foo");
+/// ct_select(0x00, b"foo", b"bar", &mut out);
+/// assert_eq!(&out, b"bar");
+/// ```
+pub fn ct_select(mask: u8, a: &[u8], b: &[u8], out: &mut [u8]) {
+    out.copy_from_slice(b);
+    with_dit(|| ct_select_in_place_impl(mask, a, out))
+}
+
+/// Copies a byte string in constant time, if a condition is true.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_copy_if;
+///
+/// let mut dst = *b"foo";
+/// ct_copy_if(false, &mut dst, b"bar");
+/// assert_eq!(&dst, b"foo");
+/// ct_copy_if(true, &mut dst, b"bar");
+/// assert_eq!(&dst, b"bar");
+/// ```
+pub fn ct_copy_if(cond: bool, dst: &mut [u8], src: &[u8]) {
+    with_dit(|| ct_select_in_place_impl(bool_mask(cond), src, dst))
+}
+
+/// Swaps two byte strings in constant time, if a condition is true.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_swap;
+///
+/// let (mut a, mut b) = (*b"foo", *b"bar");
+/// ct_swap(false, &mut a, &mut b);
+/// assert_eq!((&a, &b), (b"foo", b"bar"));
+/// ct_swap(true, &mut a, &mut b);
+/// assert_eq!((&a, &b), (b"bar", b"foo"));
+/// ```
+pub fn ct_swap(cond: bool, a: &mut [u8], b: &mut [u8]) {
+    with_dit(|| ct_swap_impl(bool_mask(cond), a, b))
+}
+
+/// Swaps two equal-sized slices of unsigned integers in constant time, if a condition is true.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_swap_elements;
+///
+/// let (mut a, mut b) = ([1u64, 2, 3], [4u64, 5, 6]);
+/// ct_swap_elements(true, &mut a, &mut b);
+/// assert_eq!((a, b), ([4, 5, 6], [1, 2, 3]));
+/// ```
+pub fn ct_swap_elements<T: Unsigned>(cond: bool, a: &mut [T], b: &mut [T]) {
+    with_dit(|| ct_swap_elements_impl(bool_mask(cond), a, b))
+}
+
+/// Reads one entry of a table in constant time, without revealing which one.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_lookup;
+///
+/// let mut out = [0; 2];
+/// constant_time_lookup(&[[1, 2], [3, 4], [5, 6]], 1, &mut out);
+/// assert_eq!(out, [3, 4]);
+/// ```
+pub fn constant_time_lookup<const W: usize>(
+    table: &[[u8; W]],
+    secret_index: usize,
+    out: &mut [u8; W],
+) {
+    with_dit(|| {
+        out.fill(0);
+        for (i, entry) in table.iter().enumerate() {
+            ct_select_in_place_impl(index_eq_mask(i, secret_index), entry, out);
+        }
+    })
+}
+
+/// Checks and removes PKCS#7 padding in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_pkcs7_unpad;
+///
+/// assert_eq!(constant_time_pkcs7_unpad(b"foo\x05\x05\x05\x05\x05", 8), (0xFF, 3));
+/// assert_eq!(constant_time_pkcs7_unpad(b"foo\x05\x05\x05\x04\x05", 8), (0x00, 0));
+/// ```
+#[must_use]
+pub fn constant_time_pkcs7_unpad(block: &[u8], block_size: usize) -> (u8, usize) {
+    assert!(
+        (1..=255).contains(&block_size),
+        "block size must be between 1 and 255"
+    );
+
+    if block.is_empty() || !block.len().is_multiple_of(block_size) {
+        return (0, 0);
+    }
+
+    with_dit(|| {
+        let (tmp, pad) = constant_time_pkcs7_check_impl(block, block_size);
+        let mask = eq_mask_u8(tmp);
+        let len = block.len().wrapping_sub(pad as usize);
+        (mask, len & usize::from(mask & 1).wrapping_neg())
+    })
+}
+
+/// Checks PKCS#1 v1.5 encryption padding in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_pkcs1v15_unpad;
+///
+/// let em = b"\x00\x02\x11\x22\x33\x44\x55\x66\x77\x88\x00foo";
+/// assert_eq!(constant_time_pkcs1v15_unpad(em), (0xFF, 11));
+/// assert_eq!(constant_time_pkcs1v15_unpad(&em[1..]), (0x00, 0));
+/// ```
+#[must_use]
+pub fn constant_time_pkcs1v15_unpad(em: &[u8]) -> (u8, usize) {
+    if em.len() < 11 {
+        return (0, 0);
+    }
+
+    with_dit(|| {
+        let (tmp, sep) = constant_time_pkcs1v15_check_impl(em);
+        let mask = eq_mask_u8(tmp);
+        (mask, (sep + 1) & usize::from(mask & 1).wrapping_neg())
+    })
+}
+
+/// Checks OAEP encryption padding in constant time, after unmasking.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_oaep_unpad;
+///
+/// let em = b"\x00SSLL\x00\x00\x01foo";
+/// assert_eq!(constant_time_oaep_unpad(em, b"LL"), (0xFF, 8));
+/// assert_eq!(constant_time_oaep_unpad(em, b"LX"), (0x00, 0));
+/// ```
+#[must_use]
+pub fn constant_time_oaep_unpad(em: &[u8], label_hash: &[u8]) -> (u8, usize) {
+    if em.len() < 2 * label_hash.len() + 2 {
+        return (0, 0);
+    }
+
+    with_dit(|| {
+        let (tmp, sep) = constant_time_oaep_check_impl(em, label_hash);
+        let mask = eq_mask_u8(tmp);
+        (mask, (sep + 1) & usize::from(mask & 1).wrapping_neg())
+    })
+}
+
+/// Checks whether a little-endian number is less than another in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_lt_le_bytes;
+///
+/// assert!(constant_time_lt_le_bytes(&[0xFF, 0x01], &[0x00, 0x02]));
+/// assert!(!constant_time_lt_le_bytes(&[0x00, 0x02], &[0x00, 0x02]));
+/// ```
+#[must_use]
+pub fn constant_time_lt_le_bytes<const N: usize>(a: &[u8; N], b: &[u8; N]) -> bool {
+    with_dit(|| constant_time_lt_bytes_impl(a.iter().zip(b)) != 0)
+}
+
+/// Checks whether a big-endian number is less than another in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_lt_be_bytes;
+///
+/// assert!(constant_time_lt_be_bytes(&[0x01, 0xFF], &[0x02, 0x00]));
+/// assert!(!constant_time_lt_be_bytes(&[0x02, 0x00], &[0x02, 0x00]));
+/// ```
+#[must_use]
+pub fn constant_time_lt_be_bytes<const N: usize>(a: &[u8; N], b: &[u8; N]) -> bool {
+    with_dit(|| constant_time_lt_bytes_impl(a.iter().zip(b).rev()) != 0)
 }
 
 #[cfg(test)]
@@ -0,0 +1,128 @@
+//! Public access to the data independent timing mode used by the comparisons.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+#[cfg(all(target_arch = "aarch64", not(miri)))]
+use crate::dit::{SavedDit, enter_dit, leave_dit};
+
+/// Runs code with the hardware DIT feature or equivalent enabled when possible.
+///
+/// This is the same protection used internally by every comparison in this crate. On aarch64,
+/// it enables `PSTATE.DIT` when `FEAT_DIT` is implemented, followed by a speculation barrier, and
+/// restores the previous value afterwards, even if the closure panics. On other targets, it
+/// simply calls the closure.
+///
+/// Calls can be nested, and nested with [`DitGuard`]; each one restores the state it found on
+/// entry, so the mode stays enabled until the outermost one returns.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::{constant_time_eq, with_data_independent_timing};
+///
+/// let tag = with_data_independent_timing(|| {
+///     // Secret-dependent arithmetic goes here.
+///     [0x5a; 16]
+/// });
+///
+/// assert!(with_data_independent_timing(|| constant_time_eq(&tag, &[0x5a; 16])));
+/// ```
+#[inline]
+pub fn with_data_independent_timing<T, F>(f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    crate::with_dit(f)
+}
+
+/// Keeps the hardware DIT feature or equivalent enabled while it is alive.
+///
+/// Creating the guard enables the same protection as [`with_data_independent_timing`], and
+/// dropping it restores the previous state. On targets without DIT, it does nothing.
+///
+/// Nested guards are created with [`DitGuard::nest`], which borrows the outer guard, so the
+/// compiler ensures the outer guard is dropped last. If a guard is leaked, the mode stays enabled.
+/// Since the mode belongs to the current thread, the guard cannot be sent to another thread.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::{DitGuard, constant_time_eq};
+///
+/// let _guard = DitGuard::new();
+/// // Secret-dependent arithmetic goes here.
+/// assert!(constant_time_eq(b"foo", b"foo"));
+/// ```
+#[must_use = "the previous state is restored as soon as the guard is dropped"]
+pub struct DitGuard<'a> {
+    #[cfg(all(target_arch = "aarch64", not(miri)))]
+    saved: SavedDit,
+    // A nested guard must not outlive the guard it was created from.
+    _outer: PhantomData<&'a ()>,
+    // The saved state belongs to the current thread.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl DitGuard<'static> {
+    /// Enables the hardware DIT feature or equivalent when possible.
+    #[allow(clippy::new_without_default)] // Creating a guard has side effects.
+    #[inline]
+    pub fn new() -> Self {
+        DitGuard {
+            #[cfg(all(target_arch = "aarch64", not(miri)))]
+            saved: enter_dit(),
+            _outer: PhantomData,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl DitGuard<'_> {
+    /// Creates a nested guard, which must be dropped before this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use constant_time_eq::DitGuard;
+    ///
+    /// let outer = DitGuard::new();
+    /// let inner = outer.nest();
+    /// drop(inner);
+    /// drop(outer);
+    /// ```
+    ///
+    /// Dropping the outer guard first does not compile:
+    ///
+    /// ```compile_fail
+    /// use constant_time_eq::DitGuard;
+    ///
+    /// let outer = DitGuard::new();
+    /// let inner = outer.nest();
+    /// drop(outer);
+    /// drop(inner);
+    /// ```
+    #[inline]
+    pub fn nest(&self) -> DitGuard<'_> {
+        DitGuard {
+            #[cfg(all(target_arch = "aarch64", not(miri)))]
+            saved: enter_dit(),
+            _outer: PhantomData,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl Drop for DitGuard<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        #[cfg(all(target_arch = "aarch64", not(miri)))]
+        leave_dit(self.saved);
+    }
+}
+
+impl fmt::Debug for DitGuard<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DitGuard")
+    }
+}
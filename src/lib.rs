@@ -13,6 +13,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(clippy::undocumented_unsafe_blocks)]
 
+use core::cmp::Ordering;
+
 #[doc(hidden)]
 pub mod classic;
 
@@ -49,6 +51,57 @@ use neon as simd;
 )))]
 use generic as simd;
 
+mod state;
+
+pub use state::ConstantTimeEqState;
+
+#[cfg(feature = "std")]
+pub use state::VerifyingWriter;
+
+mod unsigned;
+
+pub use unsigned::Unsigned;
+
+mod traits;
+
+pub use traits::ConstantTimeEq;
+
+mod base64;
+
+pub use base64::Base64Alphabet;
+
+/// Derives [`ConstantTimeEq`] for a struct, comparing all its fields in constant time.
+///
+/// The results of the field comparisons are combined without short-circuiting. Fields marked
+/// with `#[ct_eq(skip)]` are not compared.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ConstantTimeEq;
+///
+/// #[derive(ConstantTimeEq)]
+/// struct Credentials {
+///     user: Vec<u8>,
+///     key: [u8; 32],
+///     #[ct_eq(skip)]
+///     last_used: u64,
+/// }
+///
+/// let a = Credentials { user: b"user".to_vec(), key: [7; 32], last_used: 1 };
+/// let b = Credentials { user: b"user".to_vec(), key: [7; 32], last_used: 2 };
+/// assert!(a.ct_eq(&b));
+/// ```
+#[cfg(feature = "derive")]
+pub use constant_time_eq_derive::ConstantTimeEq;
+
+mod secret;
+
+pub use secret::Secret;
+
+#[cfg(feature = "std")]
+pub use secret::SecretBytes;
+
 #[cfg(all(target_arch = "aarch64", not(miri)))]
 #[doc(hidden)]
 pub mod dit;
@@ -56,6 +109,10 @@ pub mod dit;
 #[cfg(all(target_arch = "aarch64", not(miri)))]
 use dit::with_dit;
 
+mod guard;
+
+pub use guard::{DitGuard, with_data_independent_timing};
+
 /// Runs code with the hardware DIT feature or equivalent enabled when possible.
 #[cfg(any(not(target_arch = "aarch64"), miri))]
 #[inline(always)]
@@ -153,3 +210,1027 @@ pub fn constant_time_eq_32(a: &[u8; 32], b: &[u8; 32]) -> bool {
 pub fn constant_time_eq_64(a: &[u8; 64], b: &[u8; 64]) -> bool {
     constant_time_eq_n(a, b)
 }
+
+// Prefix and suffix variants.
+
+/// Checks whether a byte string starts with a prefix in constant time.
+///
+/// Exactly `prefix.len()` bytes are compared, so the time of the comparison depends only on the
+/// length of the prefix, and not on the length of the haystack.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_starts_with;
+///
+/// assert!(constant_time_starts_with(b"foobar", b"foo"));
+/// assert!(!constant_time_starts_with(b"foobar", b"bar"));
+/// # assert!(constant_time_starts_with(b"foobar", b""));
+///
+/// // Shorter than the prefix, so won't take constant time.
+/// assert!(!constant_time_starts_with(b"fo", b"foo"));
+/// ```
+#[must_use]
+pub fn constant_time_starts_with(haystack: &[u8], prefix: &[u8]) -> bool {
+    match haystack.get(..prefix.len()) {
+        Some(head) => simd::constant_time_eq(head, prefix),
+        None => false,
+    }
+}
+
+/// Checks whether a byte string ends with a suffix in constant time.
+///
+/// Exactly `suffix.len()` bytes are compared, so the time of the comparison depends only on the
+/// length of the suffix, and not on the length of the haystack.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_ends_with;
+///
+/// assert!(constant_time_ends_with(b"foobar", b"bar"));
+/// assert!(!constant_time_ends_with(b"foobar", b"foo"));
+/// # assert!(constant_time_ends_with(b"foobar", b""));
+///
+/// // Shorter than the suffix, so won't take constant time.
+/// assert!(!constant_time_ends_with(b"ar", b"bar"));
+/// ```
+#[must_use]
+pub fn constant_time_ends_with(haystack: &[u8], suffix: &[u8]) -> bool {
+    match haystack.len().checked_sub(suffix.len()) {
+        Some(start) => simd::constant_time_eq(&haystack[start..], suffix),
+        None => false,
+    }
+}
+
+// Slices of larger unsigned integers.
+
+/// Compares two equal-sized slices of unsigned integers in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_elements;
+///
+/// assert!(constant_time_eq_elements(&[1u32, 2, 3], &[1, 2, 3]));
+/// assert!(!constant_time_eq_elements(&[1u64, 2, 3], &[1, 2, 4]));
+///
+/// // Not equal-sized, so won't take constant time.
+/// assert!(!constant_time_eq_elements(&[1u16, 2, 3], &[1, 2]));
+/// ```
+#[must_use]
+pub fn constant_time_eq_elements<T: Unsigned>(a: &[T], b: &[T]) -> bool {
+    simd::constant_time_eq_elements(a, b)
+}
+
+/// Compares two fixed-size arrays of unsigned integers in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_elements_n;
+///
+/// assert!(constant_time_eq_elements_n(&[3u32; 16], &[3; 16]));
+/// assert!(!constant_time_eq_elements_n(&[3u128; 4], &[7; 4]));
+/// ```
+#[must_use]
+pub fn constant_time_eq_elements_n<T: Unsigned, const N: usize>(a: &[T; N], b: &[T; N]) -> bool {
+    simd::constant_time_eq_elements_n(a, b)
+}
+
+// Mask variants, for combining several checks without branches.
+
+/// Compares two equal-sized byte strings in constant time, returning a mask.
+///
+/// Returns `0xFF` if both inputs are equal, `0` otherwise. Unlike a `bool`, the mask can be
+/// combined with other masks using [`constant_time_mask_and`] and [`constant_time_mask_or`]
+/// without giving the optimizer an opportunity to introduce branches.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::{constant_time_eq_mask, constant_time_mask_and};
+///
+/// assert_eq!(constant_time_eq_mask(b"foo", b"foo"), 0xFF);
+/// assert_eq!(constant_time_eq_mask(b"foo", b"bar"), 0);
+///
+/// let mac_ok = constant_time_eq_mask(b"tag", b"tag");
+/// let padding_ok = constant_time_eq_mask(b"\x02\x02", b"\x02\x03");
+/// assert_eq!(constant_time_mask_and(mac_ok, padding_ok), 0);
+///
+/// // Not equal-sized, so won't take constant time.
+/// assert_eq!(constant_time_eq_mask(b"foo", b""), 0);
+/// ```
+#[must_use]
+pub fn constant_time_eq_mask(a: &[u8], b: &[u8]) -> u8 {
+    simd::constant_time_eq_mask(a, b)
+}
+
+/// Compares two equal-sized byte strings in constant time, returning a 64-bit mask.
+///
+/// Returns `!0` if both inputs are equal, `0` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_mask_u64;
+///
+/// assert_eq!(constant_time_eq_mask_u64(b"foo", b"foo"), !0);
+/// assert_eq!(constant_time_eq_mask_u64(b"foo", b"bar"), 0);
+/// ```
+#[must_use]
+pub fn constant_time_eq_mask_u64(a: &[u8], b: &[u8]) -> u64 {
+    simd::constant_time_eq_mask_u64(a, b)
+}
+
+/// Compares two fixed-size byte strings in constant time, returning a mask.
+///
+/// Returns `0xFF` if both inputs are equal, `0` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_n_mask;
+///
+/// assert_eq!(constant_time_eq_n_mask(&[3; 20], &[3; 20]), 0xFF);
+/// assert_eq!(constant_time_eq_n_mask(&[3; 20], &[7; 20]), 0);
+/// ```
+#[must_use]
+pub fn constant_time_eq_n_mask<const N: usize>(a: &[u8; N], b: &[u8; N]) -> u8 {
+    simd::constant_time_eq_n_mask(a, b)
+}
+
+/// Compares two fixed-size byte strings in constant time, returning a 64-bit mask.
+///
+/// Returns `!0` if both inputs are equal, `0` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_n_mask_u64;
+///
+/// assert_eq!(constant_time_eq_n_mask_u64(&[3; 20], &[3; 20]), !0);
+/// assert_eq!(constant_time_eq_n_mask_u64(&[3; 20], &[7; 20]), 0);
+/// ```
+#[must_use]
+pub fn constant_time_eq_n_mask_u64<const N: usize>(a: &[u8; N], b: &[u8; N]) -> u64 {
+    simd::constant_time_eq_n_mask_u64(a, b)
+}
+
+/// Combines two masks with a bitwise AND, hiding them from the optimizer.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_mask_and;
+///
+/// assert_eq!(constant_time_mask_and(0xFF, 0xFF), 0xFF);
+/// assert_eq!(constant_time_mask_and(0xFF, 0), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn constant_time_mask_and(a: u8, b: u8) -> u8 {
+    generic::constant_time_mask_and(a, b)
+}
+
+/// Combines two masks with a bitwise OR, hiding them from the optimizer.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_mask_or;
+///
+/// assert_eq!(constant_time_mask_or(0xFF, 0), 0xFF);
+/// assert_eq!(constant_time_mask_or(0, 0), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn constant_time_mask_or(a: u8, b: u8) -> u8 {
+    generic::constant_time_mask_or(a, b)
+}
+
+/// Combines two 64-bit masks with a bitwise AND, hiding them from the optimizer.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_mask_and_u64;
+///
+/// assert_eq!(constant_time_mask_and_u64(!0, !0), !0);
+/// assert_eq!(constant_time_mask_and_u64(!0, 0), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn constant_time_mask_and_u64(a: u64, b: u64) -> u64 {
+    generic::constant_time_mask_and_u64(a, b)
+}
+
+/// Combines two 64-bit masks with a bitwise OR, hiding them from the optimizer.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_mask_or_u64;
+///
+/// assert_eq!(constant_time_mask_or_u64(!0, 0), !0);
+/// assert_eq!(constant_time_mask_or_u64(0, 0), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn constant_time_mask_or_u64(a: u64, b: u64) -> u64 {
+    generic::constant_time_mask_or_u64(a, b)
+}
+
+// Bit-masked variants.
+
+/// Compares two equal-sized byte strings in constant time, ignoring the bits not set in a mask.
+///
+/// Both inputs are compared as if each byte was ANDed with the corresponding byte of the mask,
+/// which must have the same length. This is useful for formats with reserved bits, or for
+/// truncated values which do not end on a byte boundary.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_masked;
+///
+/// // Only the 12 most significant bits are compared.
+/// assert!(constant_time_eq_masked(b"\x12\x34", b"\x12\x35", b"\xFF\xF0"));
+/// assert!(!constant_time_eq_masked(b"\x12\x34", b"\x12\x44", b"\xFF\xF0"));
+///
+/// // Not equal-sized, so won't take constant time.
+/// assert!(!constant_time_eq_masked(b"\x12\x34", b"\x12\x34", b"\xFF"));
+/// ```
+#[must_use]
+pub fn constant_time_eq_masked(a: &[u8], b: &[u8], mask: &[u8]) -> bool {
+    simd::constant_time_eq_masked(a, b, mask)
+}
+
+/// Compares two fixed-size byte strings in constant time, ignoring the bits not set in a mask.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_n_masked;
+///
+/// assert!(constant_time_eq_n_masked(&[3; 20], &[7; 20], &[0xFB; 20]));
+/// assert!(!constant_time_eq_n_masked(&[3; 20], &[7; 20], &[0xFF; 20]));
+/// ```
+#[must_use]
+pub fn constant_time_eq_n_masked<const N: usize>(a: &[u8; N], b: &[u8; N], mask: &[u8; N]) -> bool {
+    simd::constant_time_eq_n_masked(a, b, mask)
+}
+
+// Case-insensitive variant.
+
+/// Compares two equal-sized byte strings in constant time, ignoring ASCII case.
+///
+/// The letters `A` to `Z` are considered equal to `a` to `z`; all other bytes, including
+/// non-ASCII bytes, must match exactly. The letters are folded without branches and without
+/// making a lowercase copy of either input.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_ignore_ascii_case;
+///
+/// assert!(constant_time_eq_ignore_ascii_case(b"DeadBeef", b"deadbeef"));
+/// assert!(!constant_time_eq_ignore_ascii_case(b"foo", b"bar"));
+/// assert!(!constant_time_eq_ignore_ascii_case(b"@", b"`"));
+///
+/// // Not equal-sized, so won't take constant time.
+/// assert!(!constant_time_eq_ignore_ascii_case(b"foo", b"FOOD"));
+/// ```
+#[must_use]
+pub fn constant_time_eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    simd::constant_time_eq_ignore_ascii_case(a, b)
+}
+
+// Membership test.
+
+/// Checks whether a byte string is equal to any of the candidates in constant time.
+///
+/// Every candidate is compared, even after a match is found, so the time of the comparison does
+/// not depend on which candidate matched, if any.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_contains;
+///
+/// let keys: [&[u8]; 2] = [b"old key", b"new key"];
+/// assert!(constant_time_contains(b"new key", &keys));
+/// assert!(!constant_time_contains(b"bad key", &keys));
+/// # assert!(!constant_time_contains(b"new key", &[]));
+///
+/// // Not equal-sized, so won't take constant time.
+/// assert!(!constant_time_contains(b"key", &keys));
+/// ```
+#[must_use]
+pub fn constant_time_contains(needle: &[u8], candidates: &[&[u8]]) -> bool {
+    simd::constant_time_contains(needle, candidates)
+}
+
+/// Finds the first candidate equal to a byte string in constant time.
+///
+/// Returns a mask, which is `0xFF` if a match was found and `0` otherwise, together with the
+/// index of the first matching candidate, or `0` if there was no match. Every candidate is
+/// compared, even after a match is found.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_position;
+///
+/// let keys: [&[u8]; 3] = [b"old key", b"new key", b"new key"];
+/// assert_eq!(constant_time_position(b"new key", &keys), (0xFF, 1));
+/// assert_eq!(constant_time_position(b"bad key", &keys), (0, 0));
+/// ```
+#[must_use]
+pub fn constant_time_position(needle: &[u8], candidates: &[&[u8]]) -> (u8, usize) {
+    simd::constant_time_position(needle, candidates)
+}
+
+// Batch variant.
+
+/// Compares many pairs of byte strings in constant time, writing one result for each pair.
+///
+/// This is equivalent to calling [`constant_time_eq`] on each pair, but the data independent
+/// timing mode is entered and left only once for the whole batch, instead of once for each pair.
+///
+/// # Panics
+///
+/// Panics if `out` and `pairs` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_batch;
+///
+/// let mut out = [false; 3];
+/// constant_time_eq_batch(&[(b"foo", b"foo"), (b"foo", b"bar"), (b"", b"")], &mut out);
+/// assert_eq!(out, [true, false, true]);
+///
+/// // Not equal-sized, so won't take constant time.
+/// constant_time_eq_batch(&[(b"foo", b"fo"), (b"foo", b"quux"), (b"", b"")], &mut out);
+/// assert_eq!(out, [false, false, true]);
+/// ```
+pub fn constant_time_eq_batch(pairs: &[(&[u8], &[u8])], out: &mut [bool]) {
+    assert_eq!(
+        pairs.len(),
+        out.len(),
+        "output length must match the number of pairs"
+    );
+    simd::constant_time_eq_batch(pairs, out)
+}
+
+// All-zero check.
+
+/// Checks whether a byte string is all zeros in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_is_zero;
+///
+/// assert!(constant_time_is_zero(&[0; 20]));
+/// assert!(!constant_time_is_zero(b"foo"));
+/// assert!(!constant_time_is_zero(b"\0\0\x01"));
+/// # assert!(constant_time_is_zero(b""));
+/// ```
+#[must_use]
+pub fn constant_time_is_zero(a: &[u8]) -> bool {
+    simd::constant_time_is_zero(a)
+}
+
+/// Checks whether a fixed-size byte string is all zeros in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_is_zero_n;
+///
+/// assert!(constant_time_is_zero_n(&[0; 32]));
+/// assert!(!constant_time_is_zero_n(&[3; 32]));
+/// ```
+#[must_use]
+pub fn constant_time_is_zero_n<const N: usize>(a: &[u8; N]) -> bool {
+    simd::constant_time_is_zero_n(a)
+}
+
+// Length-hiding variant.
+
+/// Compares two byte strings of secret length in constant time.
+///
+/// Unlike [`constant_time_eq`], the time of the comparison depends only on `max_len`, and not on
+/// the length of the inputs, so it can be used when the length itself is secret (for instance,
+/// passwords or bearer tokens). Inputs of different lengths are not equal, and inputs longer
+/// than `max_len` are never equal to anything.
+///
+/// Only the number of instructions executed is independent of the lengths; the memory addresses
+/// read are not. Bytes past the end of each input are never read, and the first byte is read
+/// again in their place, or a zero byte outside the input when the input is empty. An attacker
+/// who can observe the cache access pattern (for instance, from another thread on the same core)
+/// might still learn the lengths. When that matters, store the secrets in buffers of exactly
+/// `max_len` bytes, padded so that different lengths stay distinct (for instance, with a 0x80
+/// byte followed by zeros), and compare the whole buffers with [`constant_time_eq_n`].
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_padded;
+///
+/// assert!(constant_time_eq_padded(b"foo", b"foo", 16));
+/// assert!(!constant_time_eq_padded(b"foo", b"bar", 16));
+/// assert!(!constant_time_eq_padded(b"foo", b"fo", 16));
+/// assert!(!constant_time_eq_padded(b"foo\0", b"foo", 16));
+/// # assert!(constant_time_eq_padded(b"", b"", 16));
+/// # assert!(constant_time_eq_padded(b"", b"", 0));
+///
+/// // Longer than max_len.
+/// assert!(!constant_time_eq_padded(b"foo", b"foo", 2));
+/// ```
+#[must_use]
+pub fn constant_time_eq_padded(a: &[u8], b: &[u8], max_len: usize) -> bool {
+    generic::constant_time_eq_padded(a, b, max_len)
+}
+
+// Encoded comparison.
+
+/// Compares raw bytes with a hex-encoded string in constant time.
+///
+/// The hex string is decoded and compared in a single pass, without branching on its contents.
+/// Both uppercase and lowercase digits are accepted; a string with any other character, or with
+/// the wrong length, is never equal.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_eq_hex;
+///
+/// assert!(constant_time_eq_hex(b"\xde\xad\xbe\xef", b"deadbeef"));
+/// assert!(constant_time_eq_hex(b"\xde\xad\xbe\xef", b"DeadBeef"));
+/// assert!(!constant_time_eq_hex(b"\xde\xad\xbe\xef", b"deadbeee"));
+/// assert!(!constant_time_eq_hex(b"\xde\xad\xbe\xef", b"deadbeeg"));
+/// # assert!(constant_time_eq_hex(b"", b""));
+///
+/// // Not equal-sized, so won't take constant time.
+/// assert!(!constant_time_eq_hex(b"\xde\xad\xbe\xef", b"deadbee"));
+/// assert!(!constant_time_eq_hex(b"\xde\xad\xbe\xef", b"deadbeef00"));
+/// ```
+#[must_use]
+pub fn constant_time_eq_hex(raw: &[u8], hex: &[u8]) -> bool {
+    generic::constant_time_eq_hex(raw, hex)
+}
+
+/// Compares raw bytes with Base64-encoded text in constant time.
+///
+/// The text is decoded and compared in a single pass, without branching on its contents. It may
+/// be either padded with `=` or unpadded; which one is decided by its length alone. Text with any
+/// character outside the alphabet, with non-zero unused bits in its last character, or with the
+/// wrong length, is never equal.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::{Base64Alphabet, constant_time_eq_base64};
+///
+/// assert!(constant_time_eq_base64(b"\xfb\xff", b"+/8=", Base64Alphabet::Standard));
+/// assert!(constant_time_eq_base64(b"\xfb\xff", b"+/8", Base64Alphabet::Standard));
+/// assert!(constant_time_eq_base64(b"\xfb\xff", b"-_8", Base64Alphabet::UrlSafe));
+/// assert!(!constant_time_eq_base64(b"\xfb\xff", b"-_8", Base64Alphabet::Standard));
+/// assert!(!constant_time_eq_base64(b"\xfb\xff", b"+/9", Base64Alphabet::Standard));
+/// # assert!(constant_time_eq_base64(b"", b"", Base64Alphabet::Standard));
+///
+/// // Not equal-sized, so won't take constant time.
+/// assert!(!constant_time_eq_base64(b"\xfb\xff", b"+/8==", Base64Alphabet::Standard));
+/// ```
+#[must_use]
+pub fn constant_time_eq_base64(raw: &[u8], encoded: &[u8], alphabet: Base64Alphabet) -> bool {
+    generic::constant_time_eq_base64(raw, encoded, alphabet)
+}
+
+// Lexicographic ordering.
+
+/// Lexicographically compares two byte strings in constant time.
+///
+/// Only the result is revealed, not the position of the first difference.
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use constant_time_eq::constant_time_cmp;
+///
+/// assert_eq!(constant_time_cmp(b"foo", b"foo"), Ordering::Equal);
+/// assert_eq!(constant_time_cmp(b"bar", b"baz"), Ordering::Less);
+/// assert_eq!(constant_time_cmp(b"foo", b"bar"), Ordering::Greater);
+///
+/// // Not equal-sized, so won't take constant time.
+/// assert_eq!(constant_time_cmp(b"foo", b"fo"), Ordering::Greater);
+/// assert_eq!(constant_time_cmp(b"foo", b"quux"), Ordering::Less);
+/// ```
+#[must_use]
+pub fn constant_time_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    simd::constant_time_cmp(a, b)
+}
+
+/// Lexicographically compares two fixed-size byte strings in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use constant_time_eq::constant_time_cmp_n;
+///
+/// assert_eq!(constant_time_cmp_n(&[3; 20], &[3; 20]), Ordering::Equal);
+/// assert_eq!(constant_time_cmp_n(&[3; 20], &[7; 20]), Ordering::Less);
+/// assert_eq!(constant_time_cmp_n(&[7; 20], &[3; 20]), Ordering::Greater);
+/// ```
+#[must_use]
+pub fn constant_time_cmp_n<const N: usize>(a: &[u8; N], b: &[u8; N]) -> Ordering {
+    simd::constant_time_cmp_n(a, b)
+}
+
+// Integer comparisons.
+
+/// Checks whether `a < b` in constant time, returning a mask.
+///
+/// Returns all ones if `a < b`, or zero otherwise. The result is computed from the borrow of
+/// `a - b`, with every intermediate value hidden from the optimizer, so that it cannot be turned
+/// back into a comparison followed by a branch.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_lt_mask;
+///
+/// assert_eq!(ct_lt_mask(3u8, 7), 0xFF);
+/// assert_eq!(ct_lt_mask(7u32, 7), 0);
+/// assert_eq!(ct_lt_mask(0u128, u128::MAX), u128::MAX);
+/// ```
+#[inline]
+#[must_use]
+pub fn ct_lt_mask<T: Unsigned>(a: T, b: T) -> T {
+    unsigned::lt_mask(a, b)
+}
+
+/// Checks whether `a <= b` in constant time, returning a mask.
+///
+/// Returns all ones if `a <= b`, or zero otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_le_mask;
+///
+/// assert_eq!(ct_le_mask(7u8, 7), 0xFF);
+/// assert_eq!(ct_le_mask(8u16, 7), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn ct_le_mask<T: Unsigned>(a: T, b: T) -> T {
+    unsigned::ge_mask(b, a)
+}
+
+/// Checks whether `a > b` in constant time, returning a mask.
+///
+/// Returns all ones if `a > b`, or zero otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_gt_mask;
+///
+/// assert_eq!(ct_gt_mask(7u64, 3), u64::MAX);
+/// assert_eq!(ct_gt_mask(7usize, 7), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn ct_gt_mask<T: Unsigned>(a: T, b: T) -> T {
+    unsigned::lt_mask(b, a)
+}
+
+/// Checks whether `a >= b` in constant time, returning a mask.
+///
+/// Returns all ones if `a >= b`, or zero otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_ge_mask;
+///
+/// assert_eq!(ct_ge_mask(7u8, 7), 0xFF);
+/// assert_eq!(ct_ge_mask(3u8, 7), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn ct_ge_mask<T: Unsigned>(a: T, b: T) -> T {
+    unsigned::ge_mask(a, b)
+}
+
+/// Checks whether `a < b` in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_lt;
+///
+/// assert!(ct_lt(3u32, 7));
+/// assert!(!ct_lt(7u32, 7));
+/// ```
+#[inline]
+#[must_use]
+pub fn ct_lt<T: Unsigned>(a: T, b: T) -> bool {
+    unsigned::mask_to_bool(ct_lt_mask(a, b))
+}
+
+/// Checks whether `a <= b` in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_le;
+///
+/// assert!(ct_le(7u32, 7));
+/// assert!(!ct_le(8u32, 7));
+/// ```
+#[inline]
+#[must_use]
+pub fn ct_le<T: Unsigned>(a: T, b: T) -> bool {
+    unsigned::mask_to_bool(ct_le_mask(a, b))
+}
+
+/// Checks whether `a > b` in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_gt;
+///
+/// assert!(ct_gt(8u16, 7));
+/// assert!(!ct_gt(7u16, 7));
+/// ```
+#[inline]
+#[must_use]
+pub fn ct_gt<T: Unsigned>(a: T, b: T) -> bool {
+    unsigned::mask_to_bool(ct_gt_mask(a, b))
+}
+
+/// Checks whether `a >= b` in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_ge;
+///
+/// assert!(ct_ge(7u128, 7));
+/// assert!(!ct_ge(6u128, 7));
+/// ```
+#[inline]
+#[must_use]
+pub fn ct_ge<T: Unsigned>(a: T, b: T) -> bool {
+    unsigned::mask_to_bool(ct_ge_mask(a, b))
+}
+
+/// Checks whether a little-endian number is less than another in constant time.
+///
+/// Every byte is processed, from the least significant to the most significant, propagating the
+/// borrow of `a - b` without branches. This can be used to reject non-canonical encodings, such
+/// as a scalar which is not less than the group order.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_lt_le_bytes;
+///
+/// // The order of the Ed25519 base point, in little-endian.
+/// const L: [u8; 32] = [
+///     0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+/// ];
+///
+/// let mut s = L;
+/// assert!(!constant_time_lt_le_bytes(&s, &L));
+/// s[0] -= 1;
+/// assert!(constant_time_lt_le_bytes(&s, &L));
+/// ```
+#[must_use]
+pub fn constant_time_lt_le_bytes<const N: usize>(a: &[u8; N], b: &[u8; N]) -> bool {
+    generic::constant_time_lt_le_bytes(a, b)
+}
+
+/// Checks whether a big-endian number is less than another in constant time.
+///
+/// Every byte is processed, from the least significant to the most significant, propagating the
+/// borrow of `a - b` without branches.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_lt_be_bytes;
+///
+/// assert!(constant_time_lt_be_bytes(&[0x01, 0xFF, 0xFF], &[0x02, 0x00, 0x00]));
+/// assert!(!constant_time_lt_be_bytes(&[0x02, 0x00, 0x00], &[0x02, 0x00, 0x00]));
+/// assert!(!constant_time_lt_be_bytes(&[0x02, 0x00, 0x01], &[0x02, 0x00, 0x00]));
+/// ```
+#[must_use]
+pub fn constant_time_lt_be_bytes<const N: usize>(a: &[u8; N], b: &[u8; N]) -> bool {
+    generic::constant_time_lt_be_bytes(a, b)
+}
+
+// Padding removal.
+
+/// Checks and removes PKCS#7 padding in constant time.
+///
+/// Returns a mask, `0xFF` if the padding is valid or `0x00` otherwise, and the length of the data
+/// without the padding, or zero if the padding is invalid. The mask can be combined with other
+/// masks using [`constant_time_mask_and`], or used with [`ct_select`], to avoid branching on the
+/// result until it is safe to do so.
+///
+/// All bytes of the last block are examined, whatever the padding length claimed by the last byte,
+/// so neither the timing nor the memory access pattern reveal the padding, unlike the usual checks
+/// which lead to padding oracle attacks on CBC mode.
+///
+/// The length of `block` must be a non-zero multiple of the block size, otherwise the padding is
+/// invalid; this check is not constant time, since the length is not secret.
+///
+/// # Panics
+///
+/// Panics if `block_size` is zero or greater than 255.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_pkcs7_unpad;
+///
+/// assert_eq!(constant_time_pkcs7_unpad(b"YELLOW SUBMARINE\x04\x04\x04\x04", 20), (0xFF, 16));
+/// assert_eq!(constant_time_pkcs7_unpad(b"YELLOW SUBMARINE\x04\x04\x03\x04", 20), (0x00, 0));
+/// assert_eq!(constant_time_pkcs7_unpad(b"YELLOW SUBMARINE\x04\x04\x04\x00", 20), (0x00, 0));
+/// assert_eq!(constant_time_pkcs7_unpad(b"YELLOW SUBMARINE\x04\x04\x04\x15", 20), (0x00, 0));
+///
+/// // Not a multiple of the block size, so won't take constant time.
+/// assert_eq!(constant_time_pkcs7_unpad(b"YELLOW SUBMARINE\x04\x04\x04\x04", 16), (0x00, 0));
+/// ```
+#[must_use]
+pub fn constant_time_pkcs7_unpad(block: &[u8], block_size: usize) -> (u8, usize) {
+    generic::constant_time_pkcs7_unpad(block, block_size)
+}
+
+/// Checks PKCS#1 v1.5 encryption padding in constant time, as used by RSA decryption.
+///
+/// The encoded message must be `0x00 0x02 PS 0x00 M`, where the padding `PS` has at least eight
+/// non-zero bytes. Returns a mask, `0xFF` if the encoding is valid or `0x00` otherwise, and the
+/// offset of the message `M` within `em`, or zero if the encoding is invalid.
+///
+/// Every byte of `em` is examined, and the separator is found without branches, so neither the
+/// timing nor the memory access pattern reveal where the padding ends or why it is invalid. To
+/// also avoid Bleichenbacher's attack, callers must not reveal the mask either, for instance by
+/// using [`ct_select`] to substitute a random message when the encoding is invalid.
+///
+/// An encoded message shorter than eleven bytes is always invalid; this check is not constant
+/// time, since the length is not secret.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_pkcs1v15_unpad;
+///
+/// let em = b"\x00\x02\x11\x22\x33\x44\x55\x66\x77\x88\x00foo";
+/// let (mask, offset) = constant_time_pkcs1v15_unpad(em);
+/// assert_eq!(mask, 0xFF);
+/// assert_eq!(&em[offset..], b"foo");
+///
+/// // The padding is too short.
+/// let em = b"\x00\x02\x11\x22\x33\x44\x55\x66\x77\x00foo";
+/// assert_eq!(constant_time_pkcs1v15_unpad(em), (0x00, 0));
+///
+/// // There is no separator.
+/// let em = b"\x00\x02\x11\x22\x33\x44\x55\x66\x77\x88foo";
+/// assert_eq!(constant_time_pkcs1v15_unpad(em), (0x00, 0));
+/// ```
+#[must_use]
+pub fn constant_time_pkcs1v15_unpad(em: &[u8]) -> (u8, usize) {
+    generic::constant_time_pkcs1v15_unpad(em)
+}
+
+/// Checks OAEP encryption padding in constant time, as used by RSA decryption.
+///
+/// The encoded message must have already been unmasked, and be `Y || seed || DB`, where `Y` is a
+/// zero byte, `seed` has the same length as the label hash, and the data block `DB` is the label
+/// hash, followed by any number of zero bytes, a `0x01` byte, and the message `M`. Returns a mask,
+/// `0xFF` if the encoding is valid or `0x00` otherwise, and the offset of the message `M` within
+/// `em`, or zero if the encoding is invalid.
+///
+/// Every byte of `em` is examined, and the separator is found without branches, so neither the
+/// timing nor the memory access pattern reveal where the padding ends or why it is invalid, which
+/// would allow Manger's attack.
+///
+/// An encoded message shorter than twice the length of the label hash plus two is always invalid;
+/// this check is not constant time, since the length is not secret.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_oaep_unpad;
+///
+/// let label_hash = [0x4c; 4];
+/// let em = b"\x00SEED\x4c\x4c\x4c\x4c\x00\x00\x01foo";
+/// let (mask, offset) = constant_time_oaep_unpad(em, &label_hash);
+/// assert_eq!(mask, 0xFF);
+/// assert_eq!(&em[offset..], b"foo");
+///
+/// // The label hash does not match.
+/// assert_eq!(constant_time_oaep_unpad(em, &[0x4d; 4]), (0x00, 0));
+///
+/// // There is no separator.
+/// let em = b"\x00SEED\x4c\x4c\x4c\x4c\x00\x00\x00";
+/// assert_eq!(constant_time_oaep_unpad(em, &label_hash), (0x00, 0));
+/// ```
+#[must_use]
+pub fn constant_time_oaep_unpad(em: &[u8], label_hash: &[u8]) -> (u8, usize) {
+    generic::constant_time_oaep_unpad(em, label_hash)
+}
+
+// Conditional selection.
+
+/// Selects between two equal-sized byte strings in constant time, writing the result to `out`.
+///
+/// Each bit of the result comes from `a` where the corresponding bit of the mask is set, and from
+/// `b` where it is clear. With the masks returned by [`constant_time_eq_mask`], this selects `a`
+/// when the mask is `0xFF`, and `b` when the mask is `0x00`.
+///
+/// # Panics
+///
+/// Panics if `a`, `b` and `out` do not all have the same length.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::{constant_time_eq_mask, ct_select};
+///
+/// let mut out = [0; 3];
+/// ct_select(constant_time_eq_mask(b"foo", b"foo"), b"foo", b"bar", &mut out);
+/// assert_eq!(&out, b"foo");
+/// ct_select(constant_time_eq_mask(b"foo", b"baz"), b"foo", b"bar", &mut out);
+/// assert_eq!(&out, b"bar");
+///
+/// let mut out = [0; 1];
+/// ct_select(0x0F, &[0xAB], &[0xCD], &mut out);
+/// assert_eq!(out, [0xCB]);
+/// ```
+pub fn ct_select(mask: u8, a: &[u8], b: &[u8], out: &mut [u8]) {
+    simd::ct_select(mask, a, b, out)
+}
+
+/// Selects between two fixed-size byte strings in constant time.
+///
+/// Each bit of the result comes from `a` where the corresponding bit of the mask is set, and from
+/// `b` where it is clear.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_select_n;
+///
+/// assert_eq!(ct_select_n(0xFF, &[3; 20], &[7; 20]), [3; 20]);
+/// assert_eq!(ct_select_n(0x00, &[3; 20], &[7; 20]), [7; 20]);
+/// ```
+#[must_use]
+pub fn ct_select_n<const N: usize>(mask: u8, a: &[u8; N], b: &[u8; N]) -> [u8; N] {
+    let mut out = [0; N];
+    simd::ct_select(mask, a, b, &mut out);
+    out
+}
+
+/// Copies an equal-sized byte string in constant time, if a condition is true.
+///
+/// Every byte of `dst` is read and written either way, so neither the condition nor the contents
+/// of either byte string affect the timing.
+///
+/// # Panics
+///
+/// Panics if `dst` and `src` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::{constant_time_eq, ct_copy_if};
+///
+/// let mut key = *b"default";
+/// ct_copy_if(constant_time_eq(b"foo", b"bar"), &mut key, b"session");
+/// assert_eq!(&key, b"default");
+/// ct_copy_if(constant_time_eq(b"foo", b"foo"), &mut key, b"session");
+/// assert_eq!(&key, b"session");
+/// ```
+pub fn ct_copy_if(cond: bool, dst: &mut [u8], src: &[u8]) {
+    simd::ct_copy_if(cond, dst, src)
+}
+
+/// Copies a fixed-size byte string in constant time, if a condition is true.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_copy_if_n;
+///
+/// let mut dst = [3; 20];
+/// ct_copy_if_n(false, &mut dst, &[7; 20]);
+/// assert_eq!(dst, [3; 20]);
+/// ct_copy_if_n(true, &mut dst, &[7; 20]);
+/// assert_eq!(dst, [7; 20]);
+/// ```
+pub fn ct_copy_if_n<const N: usize>(cond: bool, dst: &mut [u8; N], src: &[u8; N]) {
+    simd::ct_copy_if(cond, dst, src)
+}
+
+// Table lookup.
+
+/// Reads one entry of a table in constant time, without revealing which one.
+///
+/// Every entry of the table is read, and the one at `secret_index` is selected with a mask, so
+/// neither the timing nor the memory access pattern depend on the index. This prevents the cache
+/// from leaking the index, as it would with `table[secret_index]`.
+///
+/// If the index is out of bounds, `out` is filled with zeros. The length of the table is not
+/// secret.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::constant_time_lookup;
+///
+/// let table = [[0x63, 0x7c], [0x77, 0x7b], [0xf2, 0x6b]];
+/// let mut out = [0; 2];
+/// constant_time_lookup(&table, 2, &mut out);
+/// assert_eq!(out, [0xf2, 0x6b]);
+/// constant_time_lookup(&table, 3, &mut out);
+/// assert_eq!(out, [0, 0]);
+/// ```
+pub fn constant_time_lookup<const W: usize>(
+    table: &[[u8; W]],
+    secret_index: usize,
+    out: &mut [u8; W],
+) {
+    simd::constant_time_lookup(table, secret_index, out)
+}
+
+// Conditional swap.
+
+/// Swaps two equal-sized byte strings in constant time, if a condition is true.
+///
+/// Every byte of both strings is read and written either way, so neither the condition nor the
+/// contents of either byte string affect the timing.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_swap;
+///
+/// let (mut a, mut b) = (*b"foo", *b"bar");
+/// ct_swap(false, &mut a, &mut b);
+/// assert_eq!((&a, &b), (b"foo", b"bar"));
+/// ct_swap(true, &mut a, &mut b);
+/// assert_eq!((&a, &b), (b"bar", b"foo"));
+/// ```
+pub fn ct_swap(cond: bool, a: &mut [u8], b: &mut [u8]) {
+    simd::ct_swap(cond, a, b)
+}
+
+/// Swaps two fixed-size byte strings in constant time, if a condition is true.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_swap_n;
+///
+/// let (mut a, mut b) = ([3; 20], [7; 20]);
+/// ct_swap_n(true, &mut a, &mut b);
+/// assert_eq!((a, b), ([7; 20], [3; 20]));
+/// ```
+pub fn ct_swap_n<const N: usize>(cond: bool, a: &mut [u8; N], b: &mut [u8; N]) {
+    simd::ct_swap(cond, a, b)
+}
+
+/// Swaps two equal-sized slices of unsigned integers in constant time, if a condition is true.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ct_swap_elements;
+///
+/// let (mut a, mut b) = ([1u64, 2, 3], [4u64, 5, 6]);
+/// ct_swap_elements(true, &mut a, &mut b);
+/// assert_eq!((a, b), ([4, 5, 6], [1, 2, 3]));
+/// ```
+pub fn ct_swap_elements<T: Unsigned>(cond: bool, a: &mut [T], b: &mut [T]) {
+    simd::ct_swap_elements(cond, a, b)
+}
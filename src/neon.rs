@@ -1,11 +1,18 @@
-//! NEON implementation of `constant_time_eq` and `constant_time_eq_n`.
+//! NEON implementation of `constant_time_eq` and the other comparisons.
 
 use core::arch::asm;
-use core::mem::size_of;
+use core::mem::{size_of, take};
 
 #[cfg(target_arch = "aarch64")]
 use core::arch::aarch64::*;
 
+use core::cmp::Ordering;
+
+use crate::generic::{
+    CmpState, Hide, Word, bool_mask, constant_time_position_impl, eq_mask_u8, eq_mask_u64,
+    index_eq_mask,
+};
+use crate::unsigned::{self, Unsigned};
 use crate::with_dit;
 
 /// Equivalent to `vceqq_u8`, but hidden from the compiler.
@@ -31,6 +38,51 @@ fn vceqq_u8_hide(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
     c
 }
 
+/// Equivalent to `vceqzq_u8`, but hidden from the compiler.
+///
+/// The use of inline assembly instead of an intrinsic prevents a sufficiently
+/// smart compiler from computing the mask in other ways which might not be
+/// constant time (for instance, looping through the input and using branching
+/// to set the vector elements).
+#[must_use]
+#[inline(always)]
+fn vceqzq_u8_hide(a: uint8x16_t) -> uint8x16_t {
+    let mut c;
+    // SAFETY: this file is compiled only when NEON is available
+    // SAFETY: assembly instruction touches only these registers
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        asm!("cmeq {c:v}.16b, {a:v}.16b, #0",
+            c = lateout(vreg) c,
+            a = in(vreg) a,
+            options(pure, nomem, preserves_flags, nostack));
+    }
+    c
+}
+
+/// Equivalent to `vcgtq_u8`, but hidden from the compiler.
+///
+/// The use of inline assembly instead of an intrinsic prevents a sufficiently
+/// smart compiler from computing the mask in other ways which might not be
+/// constant time (for instance, looping through the input and using branching
+/// to set the vector elements).
+#[must_use]
+#[inline(always)]
+fn vcgtq_u8_hide(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    let mut c;
+    // SAFETY: this file is compiled only when NEON is available
+    // SAFETY: assembly instruction touches only these registers
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        asm!("cmhi {c:v}.16b, {a:v}.16b, {b:v}.16b",
+            c = lateout(vreg) c,
+            a = in(vreg) a,
+            b = in(vreg) b,
+            options(pure, nomem, preserves_flags, nostack));
+    }
+    c
+}
+
 /// Equivalent to `vandq_u8`, but hidden from the compiler.
 ///
 /// The use of inline assembly instead of an intrinsic prevents a sufficiently
@@ -53,6 +105,71 @@ fn vandq_u8_hide(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
     c
 }
 
+/// Equivalent to `vbslq_u8`, but hidden from the compiler.
+///
+/// The use of inline assembly instead of an intrinsic prevents a sufficiently
+/// smart compiler from replacing a select by a mask with a branch on the mask.
+#[must_use]
+#[inline(always)]
+fn vbslq_u8_hide(mask: uint8x16_t, a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    let mut c;
+    // SAFETY: this file is compiled only when NEON is available
+    // SAFETY: assembly instruction touches only these registers
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        asm!("bsl {m:v}.16b, {a:v}.16b, {b:v}.16b",
+            m = inlateout(vreg) mask => c,
+            a = in(vreg) a,
+            b = in(vreg) b,
+            options(pure, nomem, preserves_flags, nostack));
+    }
+    c
+}
+
+/// Equivalent to `veorq_u8`, but hidden from the compiler.
+///
+/// The use of inline assembly instead of an intrinsic prevents a sufficiently
+/// smart compiler from replacing a swap by a mask with a branch on the mask.
+#[must_use]
+#[inline(always)]
+fn veorq_u8_hide(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    let mut c;
+    // SAFETY: this file is compiled only when NEON is available
+    // SAFETY: assembly instruction touches only these registers
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        asm!("eor {c:v}.16b, {a:v}.16b, {b:v}.16b",
+            c = lateout(vreg) c,
+            a = in(vreg) a,
+            b = in(vreg) b,
+            options(pure, nomem, preserves_flags, nostack));
+    }
+    c
+}
+
+/// Equivalent to `vaddq_u8`, but hidden from the compiler.
+///
+/// The use of inline assembly instead of an intrinsic prevents a sufficiently
+/// smart compiler from computing the sum in other ways which might not be
+/// constant time (for instance, looping through the input and using branching
+/// to set the vector elements).
+#[must_use]
+#[inline(always)]
+fn vaddq_u8_hide(a: uint8x16_t, b: uint8x16_t) -> uint8x16_t {
+    let mut c;
+    // SAFETY: this file is compiled only when NEON is available
+    // SAFETY: assembly instruction touches only these registers
+    #[cfg(target_arch = "aarch64")]
+    unsafe {
+        asm!("add {c:v}.16b, {a:v}.16b, {b:v}.16b",
+            c = lateout(vreg) c,
+            a = in(vreg) a,
+            b = in(vreg) b,
+            options(pure, nomem, preserves_flags, nostack));
+    }
+    c
+}
+
 /// Equivalent to `vshrn_n_u16(..., 4)`, but hidden from the compiler.
 ///
 /// The use of inline assembly instead of an intrinsic prevents a sufficiently
@@ -98,6 +215,16 @@ fn vld1q_u8_safe(src: &[u8]) -> uint8x16_t {
     unsafe { vld1q_u8(src.as_ptr()) }
 }
 
+/// Safe equivalent to `vst1q_u8` for byte slices.
+#[inline(always)]
+fn vst1q_u8_safe(dst: &mut [u8], a: uint8x16_t) {
+    assert_eq!(dst.len(), size_of::<uint8x16_t>());
+
+    // SAFETY: this file is compiled only when NEON is available
+    // SAFETY: the slice has enough bytes for a `uint8x16_t`
+    unsafe { vst1q_u8(dst.as_mut_ptr(), a) }
+}
+
 /// Safe equivalent to `vld1q_u8_x2` for byte slices.
 #[must_use]
 #[inline(always)]
@@ -109,12 +236,32 @@ fn vld1q_u8_x2_safe(src: &[u8]) -> uint8x16x2_t {
     unsafe { vld1q_u8_x2(src.as_ptr()) }
 }
 
+/// Safe equivalent to `vdupq_n_u8`.
+#[must_use]
+#[inline(always)]
+fn vdupq_n_u8_safe(value: u8) -> uint8x16_t {
+    // SAFETY: this file is compiled only when NEON is available
+    unsafe { vdupq_n_u8(value) }
+}
+
+/// Converts the ASCII uppercase letters in a vector to lowercase, without branches.
+#[must_use]
+#[inline(always)]
+fn to_ascii_lowercase_u8x16(a: uint8x16_t) -> uint8x16_t {
+    // A byte is an uppercase letter when (byte - 'A') < 26, as an unsigned subtraction.
+    let diff = vaddq_u8_hide(a, vdupq_n_u8_safe(b'A'.wrapping_neg()));
+    let upper = vcgtq_u8_hide(vdupq_n_u8_safe(b'Z' - b'A' + 1), diff);
+    vaddq_u8_hide(a, vandq_u8_hide(upper, vdupq_n_u8_safe(0x20)))
+}
+
 /// NEON implementation of `constant_time_eq` and `constant_time_eq_n`.
+///
+/// Returns zero if both inputs are equal, non-zero otherwise.
 #[must_use]
 #[inline(always)]
-fn constant_time_eq_neon(mut a: &[u8], mut b: &[u8]) -> bool {
+fn constant_time_ne_neon(mut a: &[u8], mut b: &[u8]) -> Word {
     if a.len() != b.len() {
-        return false;
+        return !0;
     }
 
     // This statement does nothing, because a.len() == b.len() here,
@@ -176,15 +323,492 @@ fn constant_time_eq_neon(mut a: &[u8], mut b: &[u8]) -> bool {
     };
 
     // Note: be careful to not short-circuit ("tmp == 0 &&") the comparison here
-    crate::generic::constant_time_eq_impl(a, b, tmp)
+    crate::generic::constant_time_ne_impl(a, b, tmp)
 }
 
 #[must_use]
 pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
-    with_dit(|| constant_time_eq_neon(a, b))
+    with_dit(|| constant_time_ne_neon(a, b) == 0)
 }
 
 #[must_use]
 pub fn constant_time_eq_n<const N: usize>(a: &[u8; N], b: &[u8; N]) -> bool {
-    with_dit(|| constant_time_eq_neon(&a[..], &b[..]))
+    with_dit(|| constant_time_ne_neon(&a[..], &b[..]) == 0)
+}
+
+#[must_use]
+pub(crate) fn constant_time_ne(a: &[u8], b: &[u8]) -> Word {
+    with_dit(|| constant_time_ne_neon(a, b))
+}
+
+pub fn constant_time_eq_batch(pairs: &[(&[u8], &[u8])], out: &mut [bool]) {
+    with_dit(|| {
+        for (out, &(a, b)) in out.iter_mut().zip(pairs) {
+            *out = constant_time_ne_neon(a, b) == 0;
+        }
+    })
+}
+
+/// Loads a 128-bit vector worth of unsigned integers.
+#[must_use]
+#[inline(always)]
+fn load_elements<T: Unsigned>(src: &[T]) -> uint8x16_t {
+    vld1q_u8_safe(&unsigned::pack_u128(src).to_ne_bytes())
+}
+
+/// Stores a 128-bit vector worth of unsigned integers.
+#[inline(always)]
+fn store_elements<T: Unsigned>(dst: &mut [T], a: uint8x16_t) {
+    let mut tmp = [0; size_of::<uint8x16_t>()];
+    vst1q_u8_safe(&mut tmp, a);
+    unsigned::unpack_u128(u128::from_ne_bytes(tmp), dst)
+}
+
+/// NEON implementation of `constant_time_eq_elements` and `constant_time_eq_elements_n`.
+///
+/// Returns zero if both inputs are equal, non-zero otherwise.
+#[must_use]
+#[inline(always)]
+fn constant_time_ne_elements_neon<T: Unsigned>(mut a: &[T], mut b: &[T]) -> Word {
+    if a.len() != b.len() {
+        return !0;
+    }
+
+    // This statement does nothing, because a.len() == b.len() here,
+    // but it makes the optimizer elide some useless bounds checks.
+    b = &b[..a.len()];
+
+    let lanes = unsigned::lanes::<T>();
+
+    let tmp = if a.len() >= lanes {
+        let tmpa = load_elements(&a[..lanes]);
+        let tmpb = load_elements(&b[..lanes]);
+
+        a = &a[lanes..];
+        b = &b[lanes..];
+
+        let mut mask = vceqq_u8_hide(tmpa, tmpb);
+
+        while a.len() >= lanes {
+            let tmpa = load_elements(&a[..lanes]);
+            let tmpb = load_elements(&b[..lanes]);
+
+            a = &a[lanes..];
+            b = &b[lanes..];
+
+            let tmp = vceqq_u8_hide(tmpa, tmpb);
+
+            mask = vandq_u8_hide(mask, tmp);
+        }
+
+        get_mask_u64(mask) ^ !0
+    } else {
+        0
+    };
+
+    // Note: be careful to not short-circuit ("tmp == 0 &&") the comparison here
+    crate::generic::constant_time_ne_elements_impl(a, b, tmp)
+}
+
+#[must_use]
+pub fn constant_time_eq_elements<T: Unsigned>(a: &[T], b: &[T]) -> bool {
+    with_dit(|| constant_time_ne_elements_neon(a, b) == 0)
+}
+
+#[must_use]
+pub fn constant_time_eq_elements_n<T: Unsigned, const N: usize>(a: &[T; N], b: &[T; N]) -> bool {
+    with_dit(|| constant_time_ne_elements_neon(&a[..], &b[..]) == 0)
+}
+
+#[must_use]
+pub fn constant_time_eq_mask(a: &[u8], b: &[u8]) -> u8 {
+    with_dit(|| eq_mask_u8(constant_time_ne_neon(a, b)))
+}
+
+#[must_use]
+pub fn constant_time_eq_mask_u64(a: &[u8], b: &[u8]) -> u64 {
+    with_dit(|| eq_mask_u64(constant_time_ne_neon(a, b)))
+}
+
+#[must_use]
+pub fn constant_time_eq_n_mask<const N: usize>(a: &[u8; N], b: &[u8; N]) -> u8 {
+    with_dit(|| eq_mask_u8(constant_time_ne_neon(&a[..], &b[..])))
+}
+
+#[must_use]
+pub fn constant_time_eq_n_mask_u64<const N: usize>(a: &[u8; N], b: &[u8; N]) -> u64 {
+    with_dit(|| eq_mask_u64(constant_time_ne_neon(&a[..], &b[..])))
+}
+
+/// NEON implementation of `constant_time_eq_masked` and `constant_time_eq_n_masked`.
+///
+/// Returns zero if both inputs are equal in all bits set in the mask, non-zero otherwise.
+#[must_use]
+#[inline(always)]
+fn constant_time_ne_masked_neon(mut a: &[u8], mut b: &[u8], mut mask: &[u8]) -> Word {
+    if a.len() != b.len() || a.len() != mask.len() {
+        return !0;
+    }
+
+    // These statements do nothing, because a.len() == b.len() == mask.len() here,
+    // but they make the optimizer elide some useless bounds checks.
+    b = &b[..a.len()];
+    mask = &mask[..a.len()];
+
+    const LANES: usize = 16;
+
+    let tmp = if a.len() >= LANES * 2 {
+        let tmpa = vld1q_u8_x2_safe(&a[..LANES * 2]);
+        let tmpb = vld1q_u8_x2_safe(&b[..LANES * 2]);
+        let tmpm = vld1q_u8_x2_safe(&mask[..LANES * 2]);
+
+        a = &a[LANES * 2..];
+        b = &b[LANES * 2..];
+        mask = &mask[LANES * 2..];
+
+        let mut mask0 = vceqq_u8_hide(vandq_u8_hide(tmpa.0, tmpm.0), vandq_u8_hide(tmpb.0, tmpm.0));
+        let mut mask1 = vceqq_u8_hide(vandq_u8_hide(tmpa.1, tmpm.1), vandq_u8_hide(tmpb.1, tmpm.1));
+
+        while a.len() >= LANES * 2 {
+            let tmpa = vld1q_u8_x2_safe(&a[..LANES * 2]);
+            let tmpb = vld1q_u8_x2_safe(&b[..LANES * 2]);
+            let tmpm = vld1q_u8_x2_safe(&mask[..LANES * 2]);
+
+            a = &a[LANES * 2..];
+            b = &b[LANES * 2..];
+            mask = &mask[LANES * 2..];
+
+            let tmp0 = vceqq_u8_hide(vandq_u8_hide(tmpa.0, tmpm.0), vandq_u8_hide(tmpb.0, tmpm.0));
+            let tmp1 = vceqq_u8_hide(vandq_u8_hide(tmpa.1, tmpm.1), vandq_u8_hide(tmpb.1, tmpm.1));
+
+            mask0 = vandq_u8_hide(mask0, tmp0);
+            mask1 = vandq_u8_hide(mask1, tmp1);
+        }
+
+        if a.len() >= LANES {
+            let tmpa = vld1q_u8_safe(&a[..LANES]);
+            let tmpb = vld1q_u8_safe(&b[..LANES]);
+            let tmpm = vld1q_u8_safe(&mask[..LANES]);
+
+            a = &a[LANES..];
+            b = &b[LANES..];
+            mask = &mask[LANES..];
+
+            let tmp = vceqq_u8_hide(vandq_u8_hide(tmpa, tmpm), vandq_u8_hide(tmpb, tmpm));
+
+            mask0 = vandq_u8_hide(mask0, tmp);
+        }
+
+        let mask = vandq_u8_hide(mask0, mask1);
+        get_mask_u64(mask) ^ !0
+    } else if a.len() >= LANES {
+        let tmpa = vld1q_u8_safe(&a[..LANES]);
+        let tmpb = vld1q_u8_safe(&b[..LANES]);
+        let tmpm = vld1q_u8_safe(&mask[..LANES]);
+
+        a = &a[LANES..];
+        b = &b[LANES..];
+        mask = &mask[LANES..];
+
+        let mask = vceqq_u8_hide(vandq_u8_hide(tmpa, tmpm), vandq_u8_hide(tmpb, tmpm));
+
+        get_mask_u64(mask) ^ !0
+    } else {
+        0
+    };
+
+    // Note: be careful to not short-circuit ("tmp == 0 &&") the comparison here
+    crate::generic::constant_time_ne_masked_impl(a, b, mask, tmp)
+}
+
+#[must_use]
+pub fn constant_time_eq_masked(a: &[u8], b: &[u8], mask: &[u8]) -> bool {
+    with_dit(|| constant_time_ne_masked_neon(a, b, mask) == 0)
+}
+
+#[must_use]
+pub fn constant_time_eq_n_masked<const N: usize>(a: &[u8; N], b: &[u8; N], mask: &[u8; N]) -> bool {
+    with_dit(|| constant_time_ne_masked_neon(&a[..], &b[..], &mask[..]) == 0)
+}
+
+/// NEON implementation of `constant_time_eq_ignore_ascii_case`.
+///
+/// Returns zero if both inputs are equal when ignoring ASCII case, non-zero otherwise.
+#[must_use]
+#[inline(always)]
+fn constant_time_ne_ignore_ascii_case_neon(mut a: &[u8], mut b: &[u8]) -> Word {
+    if a.len() != b.len() {
+        return !0;
+    }
+
+    // This statement does nothing, because a.len() == b.len() here,
+    // but it makes the optimizer elide some useless bounds checks.
+    b = &b[..a.len()];
+
+    const LANES: usize = 16;
+
+    let tmp = if a.len() >= LANES {
+        let tmpa = to_ascii_lowercase_u8x16(vld1q_u8_safe(&a[..LANES]));
+        let tmpb = to_ascii_lowercase_u8x16(vld1q_u8_safe(&b[..LANES]));
+
+        a = &a[LANES..];
+        b = &b[LANES..];
+
+        let mut mask = vceqq_u8_hide(tmpa, tmpb);
+
+        while a.len() >= LANES {
+            let tmpa = to_ascii_lowercase_u8x16(vld1q_u8_safe(&a[..LANES]));
+            let tmpb = to_ascii_lowercase_u8x16(vld1q_u8_safe(&b[..LANES]));
+
+            a = &a[LANES..];
+            b = &b[LANES..];
+
+            let tmp = vceqq_u8_hide(tmpa, tmpb);
+
+            mask = vandq_u8_hide(mask, tmp);
+        }
+
+        get_mask_u64(mask) ^ !0
+    } else {
+        0
+    };
+
+    // Note: be careful to not short-circuit ("tmp == 0 &&") the comparison here
+    crate::generic::constant_time_ne_ignore_ascii_case_impl(a, b, tmp)
+}
+
+#[must_use]
+pub fn constant_time_eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    with_dit(|| constant_time_ne_ignore_ascii_case_neon(a, b) == 0)
+}
+
+#[must_use]
+pub fn constant_time_contains(needle: &[u8], candidates: &[&[u8]]) -> bool {
+    with_dit(|| constant_time_position_impl(needle, candidates, constant_time_ne_neon).0 != 0)
+}
+
+#[must_use]
+pub fn constant_time_position(needle: &[u8], candidates: &[&[u8]]) -> (u8, usize) {
+    with_dit(|| constant_time_position_impl(needle, candidates, constant_time_ne_neon))
+}
+
+/// NEON implementation of `constant_time_is_zero` and `constant_time_is_zero_n`.
+#[must_use]
+#[inline(always)]
+fn constant_time_is_zero_neon(mut a: &[u8]) -> bool {
+    const LANES: usize = 16;
+
+    let tmp = if a.len() >= LANES * 2 {
+        let tmpa = vld1q_u8_x2_safe(&a[..LANES * 2]);
+
+        a = &a[LANES * 2..];
+
+        let mut mask0 = vceqzq_u8_hide(tmpa.0);
+        let mut mask1 = vceqzq_u8_hide(tmpa.1);
+
+        while a.len() >= LANES * 2 {
+            let tmpa = vld1q_u8_x2_safe(&a[..LANES * 2]);
+
+            a = &a[LANES * 2..];
+
+            let tmp0 = vceqzq_u8_hide(tmpa.0);
+            let tmp1 = vceqzq_u8_hide(tmpa.1);
+
+            mask0 = vandq_u8_hide(mask0, tmp0);
+            mask1 = vandq_u8_hide(mask1, tmp1);
+        }
+
+        if a.len() >= LANES {
+            let tmpa = vld1q_u8_safe(&a[..LANES]);
+
+            a = &a[LANES..];
+
+            let tmp = vceqzq_u8_hide(tmpa);
+
+            mask0 = vandq_u8_hide(mask0, tmp);
+        }
+
+        let mask = vandq_u8_hide(mask0, mask1);
+        get_mask_u64(mask) ^ !0
+    } else if a.len() >= LANES {
+        let tmpa = vld1q_u8_safe(&a[..LANES]);
+
+        a = &a[LANES..];
+
+        let mask = vceqzq_u8_hide(tmpa);
+
+        get_mask_u64(mask) ^ !0
+    } else {
+        0
+    };
+
+    // Note: be careful to not short-circuit ("tmp == 0 &&") the comparison here
+    crate::generic::constant_time_is_zero_impl(a, tmp)
+}
+
+#[must_use]
+pub fn constant_time_is_zero(a: &[u8]) -> bool {
+    with_dit(|| constant_time_is_zero_neon(a))
+}
+
+#[must_use]
+pub fn constant_time_is_zero_n<const N: usize>(a: &[u8; N]) -> bool {
+    with_dit(|| constant_time_is_zero_neon(&a[..]))
+}
+
+/// NEON implementation of `constant_time_cmp` and `constant_time_cmp_n`.
+///
+/// Both inputs must have the same length.
+#[must_use]
+#[inline(always)]
+fn constant_time_cmp_neon(mut a: &[u8], mut b: &[u8]) -> CmpState {
+    // This statement does nothing, because a.len() == b.len() here,
+    // but it makes the optimizer elide some useless bounds checks.
+    b = &b[..a.len()];
+
+    const LANES: usize = 16;
+
+    let mut state = CmpState::new();
+    while a.len() >= LANES {
+        let tmpa = vld1q_u8_safe(&a[..LANES]);
+        let tmpb = vld1q_u8_safe(&b[..LANES]);
+
+        a = &a[LANES..];
+        b = &b[LANES..];
+
+        let lt = get_mask_u64(vcgtq_u8_hide(tmpb, tmpa));
+        let gt = get_mask_u64(vcgtq_u8_hide(tmpa, tmpb));
+
+        // The mask puts the first byte in the lowest half-byte.
+        state.update(lt, gt);
+    }
+
+    crate::generic::constant_time_cmp_impl(a, b, state)
+}
+
+#[must_use]
+pub fn constant_time_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    with_dit(|| {
+        let len = a.len().min(b.len());
+        constant_time_cmp_neon(&a[..len], &b[..len]).finish(a.len(), b.len())
+    })
+}
+
+#[must_use]
+pub fn constant_time_cmp_n<const N: usize>(a: &[u8; N], b: &[u8; N]) -> Ordering {
+    with_dit(|| constant_time_cmp_neon(&a[..], &b[..]).finish(N, N))
+}
+
+/// NEON implementation of `ct_select` and `ct_copy_if`.
+///
+/// Replaces each bit of `dst` with the corresponding bit of `src` where the mask bit is set.
+#[inline(always)]
+fn ct_select_in_place_neon(mask: u8, mut src: &[u8], mut dst: &mut [u8]) {
+    assert_eq!(src.len(), dst.len());
+
+    const LANES: usize = 16;
+
+    let vmask = vdupq_n_u8_safe(mask.hide());
+
+    while dst.len() >= LANES {
+        let (head, tail) = take(&mut dst).split_at_mut(LANES);
+
+        let tmpa = vld1q_u8_safe(&src[..LANES]);
+        let tmpb = vld1q_u8_safe(head);
+
+        vst1q_u8_safe(head, vbslq_u8_hide(vmask, tmpa, tmpb));
+
+        src = &src[LANES..];
+        dst = tail;
+    }
+
+    crate::generic::ct_select_in_place_impl(mask, src, dst)
+}
+
+pub fn ct_select(mask: u8, a: &[u8], b: &[u8], out: &mut [u8]) {
+    out.copy_from_slice(b);
+    with_dit(|| ct_select_in_place_neon(mask, a, out))
+}
+
+pub fn ct_copy_if(cond: bool, dst: &mut [u8], src: &[u8]) {
+    with_dit(|| ct_select_in_place_neon(bool_mask(cond), src, dst))
+}
+
+pub fn constant_time_lookup<const W: usize>(
+    table: &[[u8; W]],
+    secret_index: usize,
+    out: &mut [u8; W],
+) {
+    with_dit(|| {
+        out.fill(0);
+        for (i, entry) in table.iter().enumerate() {
+            ct_select_in_place_neon(index_eq_mask(i, secret_index), entry, out);
+        }
+    })
+}
+
+/// NEON implementation of `ct_swap`.
+///
+/// Exchanges each bit of `a` with the corresponding bit of `b` where the mask bit is set.
+#[inline(always)]
+fn ct_swap_neon(mask: u8, mut a: &mut [u8], mut b: &mut [u8]) {
+    assert_eq!(a.len(), b.len());
+
+    const LANES: usize = 16;
+
+    let vmask = vdupq_n_u8_safe(mask.hide());
+
+    while a.len() >= LANES {
+        let (heada, taila) = take(&mut a).split_at_mut(LANES);
+        let (headb, tailb) = take(&mut b).split_at_mut(LANES);
+
+        let tmpa = vld1q_u8_safe(heada);
+        let tmpb = vld1q_u8_safe(headb);
+
+        let tmp = vandq_u8_hide(veorq_u8_hide(tmpa, tmpb), vmask);
+        vst1q_u8_safe(heada, veorq_u8_hide(tmpa, tmp));
+        vst1q_u8_safe(headb, veorq_u8_hide(tmpb, tmp));
+
+        a = taila;
+        b = tailb;
+    }
+
+    crate::generic::ct_swap_impl(mask, a, b)
+}
+
+pub fn ct_swap(cond: bool, a: &mut [u8], b: &mut [u8]) {
+    with_dit(|| ct_swap_neon(bool_mask(cond), a, b))
+}
+
+/// NEON implementation of `ct_swap_elements`.
+///
+/// Exchanges each bit of `a` with the corresponding bit of `b` where the mask bit is set.
+#[inline(always)]
+fn ct_swap_elements_neon<T: Unsigned>(mask: u8, mut a: &mut [T], mut b: &mut [T]) {
+    assert_eq!(a.len(), b.len());
+
+    let lanes = unsigned::lanes::<T>();
+
+    let vmask = vdupq_n_u8_safe(mask.hide());
+
+    while a.len() >= lanes {
+        let (heada, taila) = take(&mut a).split_at_mut(lanes);
+        let (headb, tailb) = take(&mut b).split_at_mut(lanes);
+
+        let tmpa = load_elements(heada);
+        let tmpb = load_elements(headb);
+
+        let tmp = vandq_u8_hide(veorq_u8_hide(tmpa, tmpb), vmask);
+        store_elements(heada, veorq_u8_hide(tmpa, tmp));
+        store_elements(headb, veorq_u8_hide(tmpb, tmp));
+
+        a = taila;
+        b = tailb;
+    }
+
+    crate::generic::ct_swap_elements_impl(mask, a, b)
+}
+
+pub fn ct_swap_elements<T: Unsigned>(cond: bool, a: &mut [T], b: &mut [T]) {
+    with_dit(|| ct_swap_elements_neon(bool_mask(cond), a, b))
 }
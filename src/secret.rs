@@ -0,0 +1,149 @@
+//! Wrappers for secret byte strings, which are compared in constant time.
+
+use core::fmt;
+
+use crate::ConstantTimeEq;
+
+/// A fixed-size secret byte string, which is compared in constant time.
+///
+/// The `==` operator on this type uses [`constant_time_eq_n`](crate::constant_time_eq_n), and
+/// the `Debug` output does not show the contents. It does not implement `Hash` or `Ord`, since
+/// these would not take constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::Secret;
+///
+/// let key = Secret::new([7; 32]);
+/// assert!(key == Secret::new([7; 32]));
+/// assert!(key != Secret::new([3; 32]));
+/// assert_eq!(format!("{:?}", key), "Secret<32>(..)");
+/// ```
+#[derive(Clone)]
+pub struct Secret<const N: usize>([u8; N]);
+
+impl<const N: usize> Secret<N> {
+    /// Wraps a fixed-size secret byte string.
+    #[must_use]
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the contents of the secret.
+    #[must_use]
+    pub const fn expose_secret(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Secret<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<const N: usize> PartialEq for Secret<N> {
+    fn eq(&self, other: &Self) -> bool {
+        crate::constant_time_eq_n(&self.0, &other.0)
+    }
+}
+
+impl<const N: usize> Eq for Secret<N> {}
+
+impl<const N: usize> ConstantTimeEq for Secret<N> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        crate::constant_time_eq_n(&self.0, &other.0)
+    }
+}
+
+impl<const N: usize> fmt::Debug for Secret<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret<{}>(..)", N)
+    }
+}
+
+/// A variable-size secret byte string, which is compared in constant time.
+///
+/// The `==` operator on this type uses [`constant_time_eq`](crate::constant_time_eq), and the
+/// `Debug` output does not show the contents. It does not implement `Hash` or `Ord`, since these
+/// would not take constant time.
+///
+/// As with `constant_time_eq`, comparing secrets of different lengths does not take constant
+/// time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::SecretBytes;
+///
+/// let token = SecretBytes::from(b"token".to_vec());
+/// assert!(token == SecretBytes::from(b"token".to_vec()));
+/// assert!(token != SecretBytes::from(b"other".to_vec()));
+/// assert_eq!(format!("{:?}", token), "SecretBytes(..)");
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct SecretBytes(Box<[u8]>);
+
+#[cfg(feature = "std")]
+impl SecretBytes {
+    /// Wraps a variable-size secret byte string.
+    #[must_use]
+    pub fn new(bytes: Box<[u8]>) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the contents of the secret.
+    #[must_use]
+    pub fn expose_secret(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Box<[u8]>> for SecretBytes {
+    fn from(bytes: Box<[u8]>) -> Self {
+        Self(bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Vec<u8>> for SecretBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes.into_boxed_slice())
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<&[u8]> for SecretBytes {
+    fn from(bytes: &[u8]) -> Self {
+        Self(bytes.into())
+    }
+}
+
+#[cfg(feature = "std")]
+impl PartialEq for SecretBytes {
+    fn eq(&self, other: &Self) -> bool {
+        crate::constant_time_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Eq for SecretBytes {}
+
+#[cfg(feature = "std")]
+impl ConstantTimeEq for SecretBytes {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        crate::constant_time_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretBytes(..)")
+    }
+}
@@ -1,11 +1,11 @@
-//! SSE2/AVX implementation of `constant_time_eq` and `constant_time_eq_n`.
+//! SSE2/AVX implementation of `constant_time_eq` and the other comparisons.
 //!
 //! Note: some microarchitectures split vector operations and/or vector registers larger than
 //! 128-bit, and might have optimizations for when one of the halves is all-zeros. To protect
 //! against that, only 128-bit vectors are used, even though larger vectors might be faster.
 
 use core::arch::asm;
-use core::mem::size_of;
+use core::mem::{size_of, take};
 
 #[cfg(target_arch = "x86")]
 use core::arch::x86::*;
@@ -13,6 +13,13 @@ use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
+use core::cmp::Ordering;
+
+use crate::generic::{
+    CmpState, Hide, Word, bool_mask, constant_time_position_impl, eq_mask_u8, eq_mask_u64,
+    index_eq_mask,
+};
+use crate::unsigned::{self, Unsigned};
 use crate::with_dit;
 
 /// Equivalent to `_mm_cmpeq_epi8`, but hidden from the compiler.
@@ -84,6 +91,175 @@ fn and_si128(a: __m128i, b: __m128i) -> __m128i {
     c
 }
 
+/// Equivalent to `_mm_andnot_si128`, but hidden from the compiler.
+///
+/// The use of inline assembly instead of an intrinsic prevents a sufficiently
+/// smart compiler from replacing a select by a mask with a branch on the mask.
+#[must_use]
+#[inline(always)]
+fn andnot_si128(a: __m128i, b: __m128i) -> __m128i {
+    let mut c;
+    // When AVX is available, the compiler will use the VEX prefix for all
+    // SIMD instructions; do the same for this inline assembly.
+    if cfg!(target_feature = "avx") {
+        // SAFETY: used only when AVX is available
+        // SAFETY: assembly instruction touches only these registers
+        unsafe {
+            asm!("vpandn {c}, {a}, {b}",
+                c = lateout(xmm_reg) c,
+                a = in(xmm_reg) a,
+                b = in(xmm_reg) b,
+                options(pure, nomem, preserves_flags, nostack));
+        }
+    } else {
+        // SAFETY: this file is compiled only when SSE2 is available
+        // SAFETY: assembly instruction touches only these registers
+        unsafe {
+            asm!("pandn {a}, {b}",
+                a = inlateout(xmm_reg) a => c,
+                b = in(xmm_reg) b,
+                options(pure, nomem, preserves_flags, nostack));
+        }
+    }
+    c
+}
+
+/// Equivalent to `_mm_or_si128`, but hidden from the compiler.
+///
+/// The use of inline assembly instead of an intrinsic prevents a sufficiently
+/// smart compiler from replacing a select by a mask with a branch on the mask.
+#[must_use]
+#[inline(always)]
+fn or_si128(a: __m128i, b: __m128i) -> __m128i {
+    let mut c;
+    // When AVX is available, the compiler will use the VEX prefix for all
+    // SIMD instructions; do the same for this inline assembly.
+    if cfg!(target_feature = "avx") {
+        // SAFETY: used only when AVX is available
+        // SAFETY: assembly instruction touches only these registers
+        unsafe {
+            asm!("vpor {c}, {a}, {b}",
+                c = lateout(xmm_reg) c,
+                a = in(xmm_reg) a,
+                b = in(xmm_reg) b,
+                options(pure, nomem, preserves_flags, nostack));
+        }
+    } else {
+        // SAFETY: this file is compiled only when SSE2 is available
+        // SAFETY: assembly instruction touches only these registers
+        unsafe {
+            asm!("por {a}, {b}",
+                a = inlateout(xmm_reg) a => c,
+                b = in(xmm_reg) b,
+                options(pure, nomem, preserves_flags, nostack));
+        }
+    }
+    c
+}
+
+/// Equivalent to `_mm_xor_si128`, but hidden from the compiler.
+///
+/// The use of inline assembly instead of an intrinsic prevents a sufficiently
+/// smart compiler from replacing a swap by a mask with a branch on the mask.
+#[must_use]
+#[inline(always)]
+fn xor_si128(a: __m128i, b: __m128i) -> __m128i {
+    let mut c;
+    // When AVX is available, the compiler will use the VEX prefix for all
+    // SIMD instructions; do the same for this inline assembly.
+    if cfg!(target_feature = "avx") {
+        // SAFETY: used only when AVX is available
+        // SAFETY: assembly instruction touches only these registers
+        unsafe {
+            asm!("vpxor {c}, {a}, {b}",
+                c = lateout(xmm_reg) c,
+                a = in(xmm_reg) a,
+                b = in(xmm_reg) b,
+                options(pure, nomem, preserves_flags, nostack));
+        }
+    } else {
+        // SAFETY: this file is compiled only when SSE2 is available
+        // SAFETY: assembly instruction touches only these registers
+        unsafe {
+            asm!("pxor {a}, {b}",
+                a = inlateout(xmm_reg) a => c,
+                b = in(xmm_reg) b,
+                options(pure, nomem, preserves_flags, nostack));
+        }
+    }
+    c
+}
+
+/// Equivalent to `_mm_add_epi8`, but hidden from the compiler.
+///
+/// The use of inline assembly instead of an intrinsic prevents a sufficiently
+/// smart compiler from computing the sum in other ways which might not be
+/// constant time (for instance, looping through the input and using branching
+/// to set the vector elements).
+#[must_use]
+#[inline(always)]
+fn add_epi8(a: __m128i, b: __m128i) -> __m128i {
+    let mut c;
+    // When AVX is available, the compiler will use the VEX prefix for all
+    // SIMD instructions; do the same for this inline assembly.
+    if cfg!(target_feature = "avx") {
+        // SAFETY: used only when AVX is available
+        // SAFETY: assembly instruction touches only these registers
+        unsafe {
+            asm!("vpaddb {c}, {a}, {b}",
+                c = lateout(xmm_reg) c,
+                a = in(xmm_reg) a,
+                b = in(xmm_reg) b,
+                options(pure, nomem, preserves_flags, nostack));
+        }
+    } else {
+        // SAFETY: this file is compiled only when SSE2 is available
+        // SAFETY: assembly instruction touches only these registers
+        unsafe {
+            asm!("paddb {a}, {b}",
+                a = inlateout(xmm_reg) a => c,
+                b = in(xmm_reg) b,
+                options(pure, nomem, preserves_flags, nostack));
+        }
+    }
+    c
+}
+
+/// Equivalent to `_mm_max_epu8`, but hidden from the compiler.
+///
+/// The use of inline assembly instead of an intrinsic prevents a sufficiently
+/// smart compiler from computing the maximum in other ways which might not be
+/// constant time (for instance, looping through the input and using branching
+/// to select the vector elements).
+#[must_use]
+#[inline(always)]
+fn max_epu8(a: __m128i, b: __m128i) -> __m128i {
+    let mut c;
+    // When AVX is available, the compiler will use the VEX prefix for all
+    // SIMD instructions; do the same for this inline assembly.
+    if cfg!(target_feature = "avx") {
+        // SAFETY: used only when AVX is available
+        // SAFETY: assembly instruction touches only these registers
+        unsafe {
+            asm!("vpmaxub {c}, {a}, {b}",
+                c = lateout(xmm_reg) c,
+                a = in(xmm_reg) a,
+                b = in(xmm_reg) b,
+                options(pure, nomem, preserves_flags, nostack));
+        }
+    } else {
+        // SAFETY: this file is compiled only when SSE2 is available
+        // SAFETY: assembly instruction touches only these registers
+        unsafe {
+            asm!("pmaxub {a}, {b}",
+                a = inlateout(xmm_reg) a => c,
+                b = in(xmm_reg) b,
+                options(pure, nomem, preserves_flags, nostack));
+        }
+    }
+    c
+}
+
 /// Equivalent to `_mm_movemask_epi8`, but hidden from the compiler.
 ///
 /// The use of inline assembly instead of an intrinsic prevents a sufficiently
@@ -120,6 +296,14 @@ fn movemask_epi8(a: __m128i) -> u32 {
     mask
 }
 
+/// Widens the result of `movemask_epi8` to a `Word`.
+#[must_use]
+#[inline(always)]
+#[allow(clippy::useless_conversion)] // Word is u32 on x86, but u64 on x86_64.
+fn mask_to_word(mask: u32) -> Word {
+    mask.into()
+}
+
 /// Safe equivalent to `_mm_loadu_si128` for byte slices.
 #[must_use]
 #[inline(always)]
@@ -131,12 +315,51 @@ fn loadu_si128(src: &[u8]) -> __m128i {
     unsafe { _mm_loadu_si128(src.as_ptr().cast::<__m128i>()) }
 }
 
+/// Safe equivalent to `_mm_storeu_si128` for byte slices.
+#[inline(always)]
+fn storeu_si128(dst: &mut [u8], a: __m128i) {
+    assert_eq!(dst.len(), size_of::<__m128i>());
+
+    // SAFETY: this file is compiled only when SSE2 is available
+    // SAFETY: the slice has enough bytes for a __m128i
+    unsafe { _mm_storeu_si128(dst.as_mut_ptr().cast::<__m128i>(), a) }
+}
+
+/// Safe equivalent to `_mm_setzero_si128`.
+#[must_use]
+#[inline(always)]
+fn setzero_si128() -> __m128i {
+    // SAFETY: this file is compiled only when SSE2 is available
+    unsafe { _mm_setzero_si128() }
+}
+
+/// Safe equivalent to `_mm_set1_epi8`.
+#[must_use]
+#[inline(always)]
+fn set1_epi8(value: u8) -> __m128i {
+    // SAFETY: this file is compiled only when SSE2 is available
+    unsafe { _mm_set1_epi8(value as i8) }
+}
+
+/// Converts the ASCII uppercase letters in a vector to lowercase, without branches.
+#[must_use]
+#[inline(always)]
+fn to_ascii_lowercase_si128(a: __m128i) -> __m128i {
+    // A byte is an uppercase letter when (byte - 'A') <= 25, as an unsigned subtraction.
+    let diff = add_epi8(a, set1_epi8(b'A'.wrapping_neg()));
+    let max = set1_epi8(b'Z' - b'A');
+    let upper = cmpeq_epi8(max_epu8(diff, max), max);
+    add_epi8(a, and_si128(upper, set1_epi8(0x20)))
+}
+
 /// SSE2/AVX implementation of `constant_time_eq` and `constant_time_eq_n`.
+///
+/// Returns zero if both inputs are equal, non-zero otherwise.
 #[must_use]
 #[inline(always)]
-fn constant_time_eq_sse2(mut a: &[u8], mut b: &[u8]) -> bool {
+fn constant_time_ne_sse2(mut a: &[u8], mut b: &[u8]) -> Word {
     if a.len() != b.len() {
-        return false;
+        return !0;
     }
 
     // This statement does nothing, because a.len() == b.len() here,
@@ -202,15 +425,505 @@ fn constant_time_eq_sse2(mut a: &[u8], mut b: &[u8]) -> bool {
     };
 
     // Note: be careful to not short-circuit ("tmp == 0 &&") the comparison here
-    crate::generic::constant_time_eq_impl(a, b, tmp.into())
+    crate::generic::constant_time_ne_impl(a, b, mask_to_word(tmp))
 }
 
 #[must_use]
 pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
-    with_dit(|| constant_time_eq_sse2(a, b))
+    with_dit(|| constant_time_ne_sse2(a, b) == 0)
 }
 
 #[must_use]
 pub fn constant_time_eq_n<const N: usize>(a: &[u8; N], b: &[u8; N]) -> bool {
-    with_dit(|| constant_time_eq_sse2(&a[..], &b[..]))
+    with_dit(|| constant_time_ne_sse2(&a[..], &b[..]) == 0)
+}
+
+#[must_use]
+pub(crate) fn constant_time_ne(a: &[u8], b: &[u8]) -> Word {
+    with_dit(|| constant_time_ne_sse2(a, b))
+}
+
+pub fn constant_time_eq_batch(pairs: &[(&[u8], &[u8])], out: &mut [bool]) {
+    with_dit(|| {
+        for (out, &(a, b)) in out.iter_mut().zip(pairs) {
+            *out = constant_time_ne_sse2(a, b) == 0;
+        }
+    })
+}
+
+/// Loads a 128-bit vector worth of unsigned integers.
+#[must_use]
+#[inline(always)]
+fn load_elements<T: Unsigned>(src: &[T]) -> __m128i {
+    loadu_si128(&unsigned::pack_u128(src).to_ne_bytes())
+}
+
+/// Stores a 128-bit vector worth of unsigned integers.
+#[inline(always)]
+fn store_elements<T: Unsigned>(dst: &mut [T], a: __m128i) {
+    let mut tmp = [0; size_of::<__m128i>()];
+    storeu_si128(&mut tmp, a);
+    unsigned::unpack_u128(u128::from_ne_bytes(tmp), dst)
+}
+
+/// SSE2/AVX implementation of `constant_time_eq_elements` and `constant_time_eq_elements_n`.
+///
+/// Returns zero if both inputs are equal, non-zero otherwise.
+#[must_use]
+#[inline(always)]
+fn constant_time_ne_elements_sse2<T: Unsigned>(mut a: &[T], mut b: &[T]) -> Word {
+    if a.len() != b.len() {
+        return !0;
+    }
+
+    // This statement does nothing, because a.len() == b.len() here,
+    // but it makes the optimizer elide some useless bounds checks.
+    b = &b[..a.len()];
+
+    let lanes = unsigned::lanes::<T>();
+
+    let tmp = if a.len() >= lanes {
+        let tmpa = load_elements(&a[..lanes]);
+        let tmpb = load_elements(&b[..lanes]);
+
+        a = &a[lanes..];
+        b = &b[lanes..];
+
+        let mut mask = cmpeq_epi8(tmpa, tmpb);
+
+        while a.len() >= lanes {
+            let tmpa = load_elements(&a[..lanes]);
+            let tmpb = load_elements(&b[..lanes]);
+
+            a = &a[lanes..];
+            b = &b[lanes..];
+
+            let tmp = cmpeq_epi8(tmpa, tmpb);
+
+            mask = and_si128(mask, tmp);
+        }
+
+        mask_to_word(movemask_epi8(mask) ^ 0xFFFF)
+    } else {
+        0
+    };
+
+    // Note: be careful to not short-circuit ("tmp == 0 &&") the comparison here
+    crate::generic::constant_time_ne_elements_impl(a, b, tmp)
+}
+
+#[must_use]
+pub fn constant_time_eq_elements<T: Unsigned>(a: &[T], b: &[T]) -> bool {
+    with_dit(|| constant_time_ne_elements_sse2(a, b) == 0)
+}
+
+#[must_use]
+pub fn constant_time_eq_elements_n<T: Unsigned, const N: usize>(a: &[T; N], b: &[T; N]) -> bool {
+    with_dit(|| constant_time_ne_elements_sse2(&a[..], &b[..]) == 0)
+}
+
+#[must_use]
+pub fn constant_time_eq_mask(a: &[u8], b: &[u8]) -> u8 {
+    with_dit(|| eq_mask_u8(constant_time_ne_sse2(a, b)))
+}
+
+#[must_use]
+pub fn constant_time_eq_mask_u64(a: &[u8], b: &[u8]) -> u64 {
+    with_dit(|| eq_mask_u64(constant_time_ne_sse2(a, b)))
+}
+
+#[must_use]
+pub fn constant_time_eq_n_mask<const N: usize>(a: &[u8; N], b: &[u8; N]) -> u8 {
+    with_dit(|| eq_mask_u8(constant_time_ne_sse2(&a[..], &b[..])))
+}
+
+#[must_use]
+pub fn constant_time_eq_n_mask_u64<const N: usize>(a: &[u8; N], b: &[u8; N]) -> u64 {
+    with_dit(|| eq_mask_u64(constant_time_ne_sse2(&a[..], &b[..])))
+}
+
+/// SSE2/AVX implementation of `constant_time_eq_masked` and `constant_time_eq_n_masked`.
+///
+/// Returns zero if both inputs are equal in all bits set in the mask, non-zero otherwise.
+#[must_use]
+#[inline(always)]
+fn constant_time_ne_masked_sse2(mut a: &[u8], mut b: &[u8], mut mask: &[u8]) -> Word {
+    if a.len() != b.len() || a.len() != mask.len() {
+        return !0;
+    }
+
+    // These statements do nothing, because a.len() == b.len() == mask.len() here,
+    // but they make the optimizer elide some useless bounds checks.
+    b = &b[..a.len()];
+    mask = &mask[..a.len()];
+
+    const LANES: usize = size_of::<__m128i>();
+
+    let tmp = if a.len() >= LANES * 2 {
+        let tmpa0 = loadu_si128(&a[..LANES]);
+        let tmpb0 = loadu_si128(&b[..LANES]);
+        let tmpm0 = loadu_si128(&mask[..LANES]);
+        let tmpa1 = loadu_si128(&a[LANES..LANES * 2]);
+        let tmpb1 = loadu_si128(&b[LANES..LANES * 2]);
+        let tmpm1 = loadu_si128(&mask[LANES..LANES * 2]);
+
+        a = &a[LANES * 2..];
+        b = &b[LANES * 2..];
+        mask = &mask[LANES * 2..];
+
+        let mut mask0 = cmpeq_epi8(and_si128(tmpa0, tmpm0), and_si128(tmpb0, tmpm0));
+        let mut mask1 = cmpeq_epi8(and_si128(tmpa1, tmpm1), and_si128(tmpb1, tmpm1));
+
+        while a.len() >= LANES * 2 {
+            let tmpa0 = loadu_si128(&a[..LANES]);
+            let tmpb0 = loadu_si128(&b[..LANES]);
+            let tmpm0 = loadu_si128(&mask[..LANES]);
+            let tmpa1 = loadu_si128(&a[LANES..LANES * 2]);
+            let tmpb1 = loadu_si128(&b[LANES..LANES * 2]);
+            let tmpm1 = loadu_si128(&mask[LANES..LANES * 2]);
+
+            a = &a[LANES * 2..];
+            b = &b[LANES * 2..];
+            mask = &mask[LANES * 2..];
+
+            let tmp0 = cmpeq_epi8(and_si128(tmpa0, tmpm0), and_si128(tmpb0, tmpm0));
+            let tmp1 = cmpeq_epi8(and_si128(tmpa1, tmpm1), and_si128(tmpb1, tmpm1));
+
+            mask0 = and_si128(mask0, tmp0);
+            mask1 = and_si128(mask1, tmp1);
+        }
+
+        if a.len() >= LANES {
+            let tmpa = loadu_si128(&a[..LANES]);
+            let tmpb = loadu_si128(&b[..LANES]);
+            let tmpm = loadu_si128(&mask[..LANES]);
+
+            a = &a[LANES..];
+            b = &b[LANES..];
+            mask = &mask[LANES..];
+
+            let tmp = cmpeq_epi8(and_si128(tmpa, tmpm), and_si128(tmpb, tmpm));
+
+            mask0 = and_si128(mask0, tmp);
+        }
+
+        let mask = and_si128(mask0, mask1);
+        movemask_epi8(mask) ^ 0xFFFF
+    } else if a.len() >= LANES {
+        let tmpa = loadu_si128(&a[..LANES]);
+        let tmpb = loadu_si128(&b[..LANES]);
+        let tmpm = loadu_si128(&mask[..LANES]);
+
+        a = &a[LANES..];
+        b = &b[LANES..];
+        mask = &mask[LANES..];
+
+        let mask = cmpeq_epi8(and_si128(tmpa, tmpm), and_si128(tmpb, tmpm));
+
+        movemask_epi8(mask) ^ 0xFFFF
+    } else {
+        0
+    };
+
+    // Note: be careful to not short-circuit ("tmp == 0 &&") the comparison here
+    crate::generic::constant_time_ne_masked_impl(a, b, mask, mask_to_word(tmp))
+}
+
+#[must_use]
+pub fn constant_time_eq_masked(a: &[u8], b: &[u8], mask: &[u8]) -> bool {
+    with_dit(|| constant_time_ne_masked_sse2(a, b, mask) == 0)
+}
+
+#[must_use]
+pub fn constant_time_eq_n_masked<const N: usize>(a: &[u8; N], b: &[u8; N], mask: &[u8; N]) -> bool {
+    with_dit(|| constant_time_ne_masked_sse2(&a[..], &b[..], &mask[..]) == 0)
+}
+
+/// SSE2/AVX implementation of `constant_time_eq_ignore_ascii_case`.
+///
+/// Returns zero if both inputs are equal when ignoring ASCII case, non-zero otherwise.
+#[must_use]
+#[inline(always)]
+fn constant_time_ne_ignore_ascii_case_sse2(mut a: &[u8], mut b: &[u8]) -> Word {
+    if a.len() != b.len() {
+        return !0;
+    }
+
+    // This statement does nothing, because a.len() == b.len() here,
+    // but it makes the optimizer elide some useless bounds checks.
+    b = &b[..a.len()];
+
+    const LANES: usize = size_of::<__m128i>();
+
+    let tmp = if a.len() >= LANES {
+        let tmpa = to_ascii_lowercase_si128(loadu_si128(&a[..LANES]));
+        let tmpb = to_ascii_lowercase_si128(loadu_si128(&b[..LANES]));
+
+        a = &a[LANES..];
+        b = &b[LANES..];
+
+        let mut mask = cmpeq_epi8(tmpa, tmpb);
+
+        while a.len() >= LANES {
+            let tmpa = to_ascii_lowercase_si128(loadu_si128(&a[..LANES]));
+            let tmpb = to_ascii_lowercase_si128(loadu_si128(&b[..LANES]));
+
+            a = &a[LANES..];
+            b = &b[LANES..];
+
+            let tmp = cmpeq_epi8(tmpa, tmpb);
+
+            mask = and_si128(mask, tmp);
+        }
+
+        movemask_epi8(mask) ^ 0xFFFF
+    } else {
+        0
+    };
+
+    // Note: be careful to not short-circuit ("tmp == 0 &&") the comparison here
+    crate::generic::constant_time_ne_ignore_ascii_case_impl(a, b, mask_to_word(tmp))
+}
+
+#[must_use]
+pub fn constant_time_eq_ignore_ascii_case(a: &[u8], b: &[u8]) -> bool {
+    with_dit(|| constant_time_ne_ignore_ascii_case_sse2(a, b) == 0)
+}
+
+#[must_use]
+pub fn constant_time_contains(needle: &[u8], candidates: &[&[u8]]) -> bool {
+    with_dit(|| constant_time_position_impl(needle, candidates, constant_time_ne_sse2).0 != 0)
+}
+
+#[must_use]
+pub fn constant_time_position(needle: &[u8], candidates: &[&[u8]]) -> (u8, usize) {
+    with_dit(|| constant_time_position_impl(needle, candidates, constant_time_ne_sse2))
+}
+
+/// SSE2/AVX implementation of `constant_time_is_zero` and `constant_time_is_zero_n`.
+#[must_use]
+#[inline(always)]
+fn constant_time_is_zero_sse2(mut a: &[u8]) -> bool {
+    const LANES: usize = size_of::<__m128i>();
+
+    let zero = setzero_si128();
+
+    let tmp = if a.len() >= LANES * 2 {
+        let tmpa0 = loadu_si128(&a[..LANES]);
+        let tmpa1 = loadu_si128(&a[LANES..LANES * 2]);
+
+        a = &a[LANES * 2..];
+
+        let mut mask0 = cmpeq_epi8(tmpa0, zero);
+        let mut mask1 = cmpeq_epi8(tmpa1, zero);
+
+        while a.len() >= LANES * 2 {
+            let tmpa0 = loadu_si128(&a[..LANES]);
+            let tmpa1 = loadu_si128(&a[LANES..LANES * 2]);
+
+            a = &a[LANES * 2..];
+
+            let tmp0 = cmpeq_epi8(tmpa0, zero);
+            let tmp1 = cmpeq_epi8(tmpa1, zero);
+
+            mask0 = and_si128(mask0, tmp0);
+            mask1 = and_si128(mask1, tmp1);
+        }
+
+        if a.len() >= LANES {
+            let tmpa = loadu_si128(&a[..LANES]);
+
+            a = &a[LANES..];
+
+            let tmp = cmpeq_epi8(tmpa, zero);
+
+            mask0 = and_si128(mask0, tmp);
+        }
+
+        let mask = and_si128(mask0, mask1);
+        movemask_epi8(mask) ^ 0xFFFF
+    } else if a.len() >= LANES {
+        let tmpa = loadu_si128(&a[..LANES]);
+
+        a = &a[LANES..];
+
+        let mask = cmpeq_epi8(tmpa, zero);
+
+        movemask_epi8(mask) ^ 0xFFFF
+    } else {
+        0
+    };
+
+    // Note: be careful to not short-circuit ("tmp == 0 &&") the comparison here
+    crate::generic::constant_time_is_zero_impl(a, mask_to_word(tmp))
+}
+
+#[must_use]
+pub fn constant_time_is_zero(a: &[u8]) -> bool {
+    with_dit(|| constant_time_is_zero_sse2(a))
+}
+
+#[must_use]
+pub fn constant_time_is_zero_n<const N: usize>(a: &[u8; N]) -> bool {
+    with_dit(|| constant_time_is_zero_sse2(&a[..]))
+}
+
+/// SSE2/AVX implementation of `constant_time_cmp` and `constant_time_cmp_n`.
+///
+/// Both inputs must have the same length.
+#[must_use]
+#[inline(always)]
+fn constant_time_cmp_sse2(mut a: &[u8], mut b: &[u8]) -> CmpState {
+    // This statement does nothing, because a.len() == b.len() here,
+    // but it makes the optimizer elide some useless bounds checks.
+    b = &b[..a.len()];
+
+    const LANES: usize = size_of::<__m128i>();
+
+    let mut state = CmpState::new();
+    while a.len() >= LANES {
+        let tmpa = loadu_si128(&a[..LANES]);
+        let tmpb = loadu_si128(&b[..LANES]);
+
+        a = &a[LANES..];
+        b = &b[LANES..];
+
+        let ne = movemask_epi8(cmpeq_epi8(tmpa, tmpb)) ^ 0xFFFF;
+        let ge = movemask_epi8(cmpeq_epi8(max_epu8(tmpa, tmpb), tmpa));
+
+        // The movemask puts the first byte in the lowest bit.
+        state.update(mask_to_word(ne & !ge), mask_to_word(ne & ge));
+    }
+
+    crate::generic::constant_time_cmp_impl(a, b, state)
+}
+
+#[must_use]
+pub fn constant_time_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    with_dit(|| {
+        let len = a.len().min(b.len());
+        constant_time_cmp_sse2(&a[..len], &b[..len]).finish(a.len(), b.len())
+    })
+}
+
+#[must_use]
+pub fn constant_time_cmp_n<const N: usize>(a: &[u8; N], b: &[u8; N]) -> Ordering {
+    with_dit(|| constant_time_cmp_sse2(&a[..], &b[..]).finish(N, N))
+}
+
+/// SSE2/AVX implementation of `ct_select` and `ct_copy_if`.
+///
+/// Replaces each bit of `dst` with the corresponding bit of `src` where the mask bit is set.
+#[inline(always)]
+fn ct_select_in_place_sse2(mask: u8, mut src: &[u8], mut dst: &mut [u8]) {
+    assert_eq!(src.len(), dst.len());
+
+    const LANES: usize = size_of::<__m128i>();
+
+    let vmask = set1_epi8(mask.hide());
+
+    while dst.len() >= LANES {
+        let (head, tail) = take(&mut dst).split_at_mut(LANES);
+
+        let tmpa = loadu_si128(&src[..LANES]);
+        let tmpb = loadu_si128(head);
+
+        storeu_si128(
+            head,
+            or_si128(and_si128(vmask, tmpa), andnot_si128(vmask, tmpb)),
+        );
+
+        src = &src[LANES..];
+        dst = tail;
+    }
+
+    crate::generic::ct_select_in_place_impl(mask, src, dst)
+}
+
+pub fn ct_select(mask: u8, a: &[u8], b: &[u8], out: &mut [u8]) {
+    out.copy_from_slice(b);
+    with_dit(|| ct_select_in_place_sse2(mask, a, out))
+}
+
+pub fn ct_copy_if(cond: bool, dst: &mut [u8], src: &[u8]) {
+    with_dit(|| ct_select_in_place_sse2(bool_mask(cond), src, dst))
+}
+
+pub fn constant_time_lookup<const W: usize>(
+    table: &[[u8; W]],
+    secret_index: usize,
+    out: &mut [u8; W],
+) {
+    with_dit(|| {
+        out.fill(0);
+        for (i, entry) in table.iter().enumerate() {
+            ct_select_in_place_sse2(index_eq_mask(i, secret_index), entry, out);
+        }
+    })
+}
+
+/// SSE2/AVX implementation of `ct_swap`.
+///
+/// Exchanges each bit of `a` with the corresponding bit of `b` where the mask bit is set.
+#[inline(always)]
+fn ct_swap_sse2(mask: u8, mut a: &mut [u8], mut b: &mut [u8]) {
+    assert_eq!(a.len(), b.len());
+
+    const LANES: usize = size_of::<__m128i>();
+
+    let vmask = set1_epi8(mask.hide());
+
+    while a.len() >= LANES {
+        let (heada, taila) = take(&mut a).split_at_mut(LANES);
+        let (headb, tailb) = take(&mut b).split_at_mut(LANES);
+
+        let tmpa = loadu_si128(heada);
+        let tmpb = loadu_si128(headb);
+
+        let tmp = and_si128(xor_si128(tmpa, tmpb), vmask);
+        storeu_si128(heada, xor_si128(tmpa, tmp));
+        storeu_si128(headb, xor_si128(tmpb, tmp));
+
+        a = taila;
+        b = tailb;
+    }
+
+    crate::generic::ct_swap_impl(mask, a, b)
+}
+
+pub fn ct_swap(cond: bool, a: &mut [u8], b: &mut [u8]) {
+    with_dit(|| ct_swap_sse2(bool_mask(cond), a, b))
+}
+
+/// SSE2/AVX implementation of `ct_swap_elements`.
+///
+/// Exchanges each bit of `a` with the corresponding bit of `b` where the mask bit is set.
+#[inline(always)]
+fn ct_swap_elements_sse2<T: Unsigned>(mask: u8, mut a: &mut [T], mut b: &mut [T]) {
+    assert_eq!(a.len(), b.len());
+
+    let lanes = unsigned::lanes::<T>();
+
+    let vmask = set1_epi8(mask.hide());
+
+    while a.len() >= lanes {
+        let (heada, taila) = take(&mut a).split_at_mut(lanes);
+        let (headb, tailb) = take(&mut b).split_at_mut(lanes);
+
+        let tmpa = load_elements(heada);
+        let tmpb = load_elements(headb);
+
+        let tmp = and_si128(xor_si128(tmpa, tmpb), vmask);
+        store_elements(heada, xor_si128(tmpa, tmp));
+        store_elements(headb, xor_si128(tmpb, tmp));
+
+        a = taila;
+        b = tailb;
+    }
+
+    crate::generic::ct_swap_elements_impl(mask, a, b)
+}
+
+pub fn ct_swap_elements<T: Unsigned>(cond: bool, a: &mut [T], b: &mut [T]) {
+    with_dit(|| ct_swap_elements_sse2(bool_mask(cond), a, b))
 }
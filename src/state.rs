@@ -0,0 +1,122 @@
+//! Incremental comparison of byte strings which arrive in pieces.
+
+use crate::generic::{Word, optimizer_hide};
+
+/// Compares two byte strings in constant time, one chunk at a time.
+///
+/// The differences found by each call to [`update`](Self::update) are accumulated, without
+/// revealing whether or where a difference was found, until [`finalize`](Self::finalize) is
+/// called.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ConstantTimeEqState;
+///
+/// let expected = b"foobarbaz";
+///
+/// let mut state = ConstantTimeEqState::new();
+/// state.update(&expected[..3], b"foo");
+/// state.update(&expected[3..], b"barbaz");
+/// assert!(state.finalize());
+///
+/// let mut state = ConstantTimeEqState::new();
+/// state.update(&expected[..3], b"fob");
+/// state.update(&expected[3..], b"barbaz");
+/// assert!(!state.finalize());
+/// ```
+#[derive(Clone, Default)]
+pub struct ConstantTimeEqState {
+    tmp: Word,
+}
+
+impl ConstantTimeEqState {
+    /// Creates a new comparison, with no differences found so far.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { tmp: 0 }
+    }
+
+    /// Compares the next chunk of both byte strings in constant time.
+    ///
+    /// Both chunks should have the same length; if they do not, the comparison will fail.
+    /// Comparing a chunk always takes the same time, even after a difference was found.
+    pub fn update(&mut self, expected: &[u8], actual: &[u8]) {
+        let cmp = optimizer_hide(crate::simd::constant_time_ne(expected, actual));
+        self.tmp = optimizer_hide(self.tmp | cmp);
+    }
+
+    /// Returns whether all chunks compared so far were equal.
+    #[must_use]
+    pub fn finalize(self) -> bool {
+        self.tmp == 0
+    }
+}
+
+/// Verifies that the bytes written to it are equal to the expected bytes, in constant time.
+///
+/// All writes succeed, whether or not the written bytes match; the result of the comparison is
+/// only available from [`finish`](Self::finish). Writing more or fewer bytes than expected makes
+/// the comparison fail, without revealing where the first difference was.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use constant_time_eq::VerifyingWriter;
+///
+/// let mut writer = VerifyingWriter::new(b"foobarbaz");
+/// writer.write_all(b"foo")?;
+/// writer.write_all(b"barbaz")?;
+/// assert!(writer.finish());
+///
+/// let mut writer = VerifyingWriter::new(b"foobarbaz");
+/// writer.write_all(b"foobar")?;
+/// assert!(!writer.finish());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+pub struct VerifyingWriter<'a> {
+    expected: &'a [u8],
+    state: ConstantTimeEqState,
+}
+
+#[cfg(feature = "std")]
+impl<'a> VerifyingWriter<'a> {
+    /// Creates a writer which compares everything written to it against `expected`.
+    #[must_use]
+    pub const fn new(expected: &'a [u8]) -> Self {
+        Self {
+            expected,
+            state: ConstantTimeEqState::new(),
+        }
+    }
+
+    /// Returns whether the bytes written were equal to the expected bytes.
+    #[must_use]
+    pub fn finish(mut self) -> bool {
+        // Missing bytes at the end make the comparison fail.
+        self.state.update(self.expected, &[]);
+        self.state.finalize()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for VerifyingWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let (head, tail) = buf.split_at(buf.len().min(self.expected.len()));
+        let (expected, rest) = self.expected.split_at(head.len());
+        self.expected = rest;
+
+        self.state.update(expected, head);
+
+        // Extra bytes past the end make the comparison fail.
+        self.state.update(&[], tail);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
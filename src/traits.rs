@@ -0,0 +1,80 @@
+//! Trait for comparing common byte containers in constant time.
+
+use crate::{constant_time_eq, constant_time_eq_n};
+
+/// Types which can be compared for equality in constant time.
+///
+/// # Examples
+///
+/// ```
+/// use constant_time_eq::ConstantTimeEq;
+///
+/// assert!(b"foo"[..].ct_eq(b"foo"));
+/// assert!([3u8; 20].ct_ne(&[7; 20]));
+/// assert!("foo".ct_eq("foo"));
+/// ```
+pub trait ConstantTimeEq<Rhs: ?Sized = Self> {
+    /// Compares two values in constant time, returning `true` if they are equal.
+    #[must_use]
+    fn ct_eq(&self, other: &Rhs) -> bool;
+
+    /// Compares two values in constant time, returning `true` if they are not equal.
+    #[must_use]
+    #[inline]
+    fn ct_ne(&self, other: &Rhs) -> bool {
+        !self.ct_eq(other)
+    }
+}
+
+impl ConstantTimeEq for [u8] {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        constant_time_eq(self, other)
+    }
+}
+
+impl<const N: usize> ConstantTimeEq for [u8; N] {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        constant_time_eq_n(self, other)
+    }
+}
+
+impl ConstantTimeEq for str {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        constant_time_eq(self.as_bytes(), other.as_bytes())
+    }
+}
+
+#[cfg(feature = "std")]
+impl ConstantTimeEq for Vec<u8> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        constant_time_eq(self, other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ConstantTimeEq for Box<[u8]> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        constant_time_eq(self, other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ConstantTimeEq for String {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        constant_time_eq(self.as_bytes(), other.as_bytes())
+    }
+}
+
+#[cfg(feature = "std")]
+impl ConstantTimeEq for std::borrow::Cow<'_, [u8]> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> bool {
+        constant_time_eq(self, other)
+    }
+}
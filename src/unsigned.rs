@@ -0,0 +1,167 @@
+//! Unsigned integer types which can be used as elements of the compared slices.
+
+use crate::generic::{Hide, Word};
+
+mod sealed {
+    use crate::generic::Word;
+
+    pub trait Sealed: Copy {
+        /// The number of elements in a 128-bit vector.
+        const LANES: usize;
+
+        /// Folds the difference between two elements into a word, which is zero only if both
+        /// elements are equal.
+        fn ne_word(self, other: Self) -> Word;
+
+        /// Packs a 128-bit vector worth of elements into a single integer.
+        fn pack_u128(src: &[Self]) -> u128;
+
+        /// Unpacks a 128-bit vector worth of elements from a single integer.
+        fn unpack_u128(value: u128, dst: &mut [Self]);
+
+        /// Exchanges each bit of `a` with the corresponding bit of `b` where the mask bit is set.
+        fn swap_masked(mask: u8, a: &mut Self, b: &mut Self);
+
+        /// Returns an all-ones mask if `self < other`, or an all-zeros mask otherwise.
+        fn lt_mask(self, other: Self) -> Self;
+
+        /// Returns an all-ones mask if `self >= other`, or an all-zeros mask otherwise.
+        fn ge_mask(self, other: Self) -> Self;
+
+        /// Converts an all-ones mask to true, and an all-zeros mask to false.
+        fn mask_to_bool(self) -> bool;
+    }
+}
+
+/// Unsigned integer types which can be compared in constant time.
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait Unsigned: sealed::Sealed + Copy {}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {
+                const LANES: usize = (u128::BITS / <$t>::BITS) as usize;
+
+                #[inline(always)]
+                fn ne_word(self, other: Self) -> Word {
+                    let diff = (self ^ other).hide();
+
+                    // Elements wider than a word are folded one word at a time.
+                    let mut tmp = 0;
+                    let mut shift = 0;
+                    while shift < <$t>::BITS {
+                        tmp |= (diff >> shift) as Word;
+                        shift += Word::BITS;
+                    }
+                    tmp
+                }
+
+                #[inline(always)]
+                fn pack_u128(src: &[Self]) -> u128 {
+                    assert_eq!(src.len(), Self::LANES);
+
+                    let mut value = 0;
+                    for (i, &element) in src.iter().enumerate() {
+                        value |= (element as u128) << (i as u32 * <$t>::BITS);
+                    }
+                    value
+                }
+
+                #[inline(always)]
+                fn unpack_u128(value: u128, dst: &mut [Self]) {
+                    assert_eq!(dst.len(), Self::LANES);
+
+                    for (i, element) in dst.iter_mut().enumerate() {
+                        *element = (value >> (i as u32 * <$t>::BITS)) as $t;
+                    }
+                }
+
+                #[inline(always)]
+                fn swap_masked(mask: u8, a: &mut Self, b: &mut Self) {
+                    let mask = (mask as $t).wrapping_mul(<$t>::MAX / 0xFF);
+                    let tmp = (*a ^ *b).hide() & mask.hide();
+                    *a ^= tmp;
+                    *b ^= tmp;
+                }
+
+                #[inline(always)]
+                fn lt_mask(self, other: Self) -> Self {
+                    let (a, b) = (self.hide(), other.hide());
+                    let diff = a.wrapping_sub(b).hide();
+                    let borrow = ((!a & b) | (!(a ^ b) & diff)).hide() >> (<$t>::BITS - 1);
+                    borrow.hide().wrapping_neg()
+                }
+
+                #[inline(always)]
+                fn ge_mask(self, other: Self) -> Self {
+                    (!self.lt_mask(other)).hide()
+                }
+
+                #[inline(always)]
+                fn mask_to_bool(self) -> bool {
+                    self.hide() & 1 != 0
+                }
+            }
+            impl Unsigned for $t {}
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// Returns the number of elements in a 128-bit vector.
+#[must_use]
+#[inline(always)]
+pub(crate) const fn lanes<T: Unsigned>() -> usize {
+    <T as sealed::Sealed>::LANES
+}
+
+/// Folds the difference between two elements into a word, which is zero only if both elements
+/// are equal.
+#[must_use]
+#[inline(always)]
+pub(crate) fn ne_word<T: Unsigned>(a: T, b: T) -> Word {
+    sealed::Sealed::ne_word(a, b)
+}
+
+/// Packs a 128-bit vector worth of elements into a single integer.
+#[must_use]
+#[inline(always)]
+pub(crate) fn pack_u128<T: Unsigned>(src: &[T]) -> u128 {
+    sealed::Sealed::pack_u128(src)
+}
+
+/// Unpacks a 128-bit vector worth of elements from a single integer.
+#[inline(always)]
+pub(crate) fn unpack_u128<T: Unsigned>(value: u128, dst: &mut [T]) {
+    sealed::Sealed::unpack_u128(value, dst)
+}
+
+/// Exchanges each bit of `a` with the corresponding bit of `b` where the mask bit is set.
+#[inline(always)]
+pub(crate) fn swap_masked<T: Unsigned>(mask: u8, a: &mut T, b: &mut T) {
+    sealed::Sealed::swap_masked(mask, a, b)
+}
+
+/// Returns an all-ones mask if `a < b`, or an all-zeros mask otherwise.
+#[must_use]
+#[inline(always)]
+pub(crate) fn lt_mask<T: Unsigned>(a: T, b: T) -> T {
+    sealed::Sealed::lt_mask(a, b)
+}
+
+/// Returns an all-ones mask if `a >= b`, or an all-zeros mask otherwise.
+#[must_use]
+#[inline(always)]
+pub(crate) fn ge_mask<T: Unsigned>(a: T, b: T) -> T {
+    sealed::Sealed::ge_mask(a, b)
+}
+
+/// Converts an all-ones mask to true, and an all-zeros mask to false.
+#[must_use]
+#[inline(always)]
+pub(crate) fn mask_to_bool<T: Unsigned>(mask: T) -> bool {
+    sealed::Sealed::mask_to_bool(mask)
+}
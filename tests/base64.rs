@@ -0,0 +1,122 @@
+#![cfg(not(miri))]
+
+mod common;
+
+use common::fill_random;
+use constant_time_eq::{Base64Alphabet, constant_time_eq_base64};
+
+const ALPHABETS: [(Base64Alphabet, &[u8; 64]); 2] = [
+    (
+        Base64Alphabet::Standard,
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    ),
+    (
+        Base64Alphabet::UrlSafe,
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+    ),
+];
+
+fn encode(raw: &[u8], symbols: &[u8; 64], padded: bool) -> Vec<u8> {
+    let mut out = Vec::new();
+    for chunk in raw.chunks(3) {
+        let mut group = [0u8; 3];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let bits = u32::from(group[0]) << 16 | u32::from(group[1]) << 8 | u32::from(group[2]);
+        for i in 0..=chunk.len() {
+            out.push(symbols[(bits >> (18 - 6 * i)) as usize & 0x3F]);
+        }
+        if padded {
+            out.resize(out.len() + 3 - chunk.len(), b'=');
+        }
+    }
+    out
+}
+
+/// Confirms that every character decodes to the expected value, or is rejected.
+#[test]
+fn exhaustive_test_base64_chars() {
+    for (alphabet, symbols) in ALPHABETS {
+        for c in 0..=255u8 {
+            let expected = symbols.iter().position(|&s| s == c);
+            for v in 0..64 {
+                let raw = [0x00, 0x00, v];
+                let encoded = [b'A', b'A', b'A', c];
+                assert_eq!(
+                    constant_time_eq_base64(&raw, &encoded, alphabet),
+                    expected == Some(usize::from(v)),
+                    "{:?} c=0x{:02x} v={}",
+                    alphabet,
+                    c,
+                    v
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_all_lengths() {
+    let mut raw = [0u8; 64];
+    fill_random(&mut raw);
+
+    for n in 0..=raw.len() {
+        let raw = &mut raw[..n];
+        for (alphabet, symbols) in ALPHABETS {
+            for padded in [false, true] {
+                let mut encoded = encode(raw, symbols, padded);
+                assert!(constant_time_eq_base64(raw, &encoded, alphabet));
+
+                for i in 0..n {
+                    raw[i] ^= 0x01;
+                    assert!(
+                        !constant_time_eq_base64(raw, &encoded, alphabet),
+                        "len={} raw[{}]",
+                        n,
+                        i
+                    );
+                    raw[i] ^= 0x01;
+                }
+
+                for i in 0..encoded.len() {
+                    let c = encoded[i];
+                    // The other alphabet's symbols are invalid, and so is misplaced padding.
+                    let others = [
+                        b'!',
+                        b'.',
+                        b'+' ^ b'-' ^ symbols[62],
+                        b'/' ^ b'_' ^ symbols[63],
+                        b'=',
+                    ];
+                    for invalid in others.into_iter().filter(|&invalid| invalid != c) {
+                        encoded[i] = invalid;
+                        assert!(
+                            !constant_time_eq_base64(raw, &encoded, alphabet),
+                            "len={} encoded[{}]={:?}",
+                            n,
+                            i,
+                            char::from(invalid)
+                        );
+                    }
+                    encoded[i] = c;
+                }
+
+                // Non-zero unused bits in the last character.
+                if n % 3 != 0 {
+                    let last = n / 3 * 4 + n % 3;
+                    let c = encoded[last];
+                    encoded[last] = symbols[symbols.iter().position(|&s| s == c).unwrap() | 1];
+                    assert!(
+                        !constant_time_eq_base64(raw, &encoded, alphabet),
+                        "len={}",
+                        n
+                    );
+                    encoded[last] = c;
+                }
+
+                // An extra character is never padding, even where the length would allow it.
+                encoded.push(b'A');
+                assert!(!constant_time_eq_base64(raw, &encoded, alphabet));
+            }
+        }
+    }
+}
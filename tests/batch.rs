@@ -0,0 +1,51 @@
+#![cfg(not(miri))]
+
+mod common;
+
+use common::fill_random;
+
+/// Confirms that each result matches the comparison of its own pair, for all lengths up to 1024
+/// bits.
+fn test_all_lengths<CTBATCH>(ctbatch: &CTBATCH)
+where
+    CTBATCH: Fn(&[(&[u8], &[u8])], &mut [bool]),
+{
+    let mut a = [0u8; 129];
+    fill_random(&mut a);
+    let mut b = a;
+    b[64] ^= 0x80;
+
+    // Use an offset of one byte, to ensure no SIMD load instructions require alignment.
+    let pairs: Vec<(&[u8], &[u8])> = (0..=128)
+        .flat_map(|n| [(&a[1..=n], &b[1..=n]), (&a[1..=n], &a[1..=n])])
+        .chain([(&a[..], &a[1..])])
+        .collect();
+    let expected: Vec<bool> = pairs.iter().map(|&(a, b)| a == b).collect();
+
+    let mut out = vec![false; pairs.len()];
+    ctbatch(&pairs, &mut out);
+    assert_eq!(out, expected);
+
+    out.fill(true);
+    ctbatch(&pairs, &mut out);
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_batch_simd() {
+    use constant_time_eq::constant_time_eq_batch;
+    test_all_lengths(&constant_time_eq_batch);
+}
+
+#[test]
+fn test_batch_generic() {
+    use constant_time_eq::generic::constant_time_eq_batch;
+    test_all_lengths(&constant_time_eq_batch);
+}
+
+#[test]
+#[should_panic]
+fn test_batch_length_mismatch() {
+    use constant_time_eq::constant_time_eq_batch;
+    constant_time_eq_batch(&[(b"foo", b"foo")], &mut [false; 2]);
+}
@@ -0,0 +1,93 @@
+#![cfg(not(miri))]
+
+mod common;
+
+use common::fill_random;
+use core::cmp::Ordering;
+
+/// Confirms that the first difference decides the result, for all lengths up to 1024 bits.
+fn test_all_lengths<CTCMP>(ctcmp: &CTCMP)
+where
+    CTCMP: Fn(&[u8], &[u8]) -> Ordering,
+{
+    let mut a = [0u8; 129];
+    fill_random(&mut a);
+    let mut b = a;
+
+    // Note: this is quadratic; do not increase the maximum length too much.
+    for n in 0..=128 {
+        // Use an offset of one byte, to ensure no SIMD load instructions require alignment.
+        let a = &mut a[1..=n];
+        let b = &mut b[1..=n];
+
+        assert_eq!(ctcmp(a, b), Ordering::Equal);
+        for i in 0..n {
+            for m in [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80] {
+                a[i] ^= m;
+                assert_eq!(
+                    ctcmp(a, b),
+                    a.cmp(&b),
+                    "len={} a[{}] mask 0x{:02x}",
+                    n,
+                    i,
+                    m
+                );
+                assert_eq!(
+                    ctcmp(b, a),
+                    b.cmp(&a),
+                    "len={} b[{}] mask 0x{:02x}",
+                    n,
+                    i,
+                    m
+                );
+
+                // A later difference in the other direction must not change the result.
+                if i + 1 < n {
+                    b[n - 1] ^= m;
+                    assert_eq!(
+                        ctcmp(a, b),
+                        a.cmp(&b),
+                        "len={} a[{}] mask 0x{:02x}",
+                        n,
+                        i,
+                        m
+                    );
+                    b[n - 1] ^= m;
+                }
+                a[i] ^= m;
+            }
+        }
+        assert_eq!(ctcmp(a, b), Ordering::Equal);
+
+        // A proper prefix is always less.
+        if n > 0 {
+            assert_eq!(ctcmp(&a[..n - 1], b), Ordering::Less);
+            assert_eq!(ctcmp(a, &b[..n - 1]), Ordering::Greater);
+        }
+    }
+}
+
+#[test]
+fn exhaustive_test_cmp_simd() {
+    use constant_time_eq::constant_time_cmp;
+    test_all_lengths(&constant_time_cmp);
+}
+
+#[test]
+fn exhaustive_test_cmp_generic() {
+    use constant_time_eq::generic::constant_time_cmp;
+    test_all_lengths(&constant_time_cmp);
+}
+
+#[test]
+fn test_cmp_n() {
+    use constant_time_eq::constant_time_cmp_n;
+
+    let mut a = [0u8; 64];
+    fill_random(&mut a);
+    let mut b = a;
+    assert_eq!(constant_time_cmp_n(&a, &b), Ordering::Equal);
+    b[17] = b[17].wrapping_add(1);
+    assert_eq!(constant_time_cmp_n(&a, &b), a.cmp(&b));
+    assert_eq!(constant_time_cmp_n(&b, &a), b.cmp(&a));
+}
@@ -0,0 +1,12 @@
+//! Helpers shared by the integration tests.
+
+/// Fills a buffer using a simple xorshift PRNG, from https://www.jstatsoft.org/article/view/v008i14
+pub fn fill_random(buf: &mut [u8]) {
+    let mut state: u32 = 2463534242;
+    buf.fill_with(|| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as u8
+    });
+}
@@ -0,0 +1,47 @@
+#![cfg(not(miri))]
+
+fn test_position<CTPOS>(ctpos: &CTPOS)
+where
+    CTPOS: Fn(&[u8], &[&[u8]]) -> (u8, usize),
+{
+    let keys: Vec<Vec<u8>> = (0..8u8).map(|i| vec![i; 40]).collect();
+    let candidates: Vec<&[u8]> = keys.iter().map(Vec::as_slice).collect();
+
+    for (i, key) in keys.iter().enumerate() {
+        assert_eq!(ctpos(key, &candidates), (0xFF, i));
+        assert_eq!(ctpos(key, &candidates[..i]), (0, 0));
+    }
+
+    // The first match wins.
+    let mut duplicated = candidates.clone();
+    duplicated.extend_from_slice(&candidates);
+    assert_eq!(ctpos(&keys[5], &duplicated), (0xFF, 5));
+
+    let mut key = keys[3].clone();
+    key[39] ^= 1;
+    assert_eq!(ctpos(&key, &candidates), (0, 0));
+    assert_eq!(ctpos(&key[..39], &candidates), (0, 0));
+}
+
+#[test]
+fn test_position_simd() {
+    use constant_time_eq::constant_time_position;
+    test_position(&constant_time_position);
+}
+
+#[test]
+fn test_position_generic() {
+    use constant_time_eq::generic::constant_time_position;
+    test_position(&constant_time_position);
+}
+
+#[test]
+fn test_contains() {
+    use constant_time_eq::constant_time_contains;
+
+    let candidates: [&[u8]; 3] = [b"first", b"second", b"third"];
+    assert!(constant_time_contains(b"second", &candidates));
+    assert!(constant_time_contains(b"third", &candidates));
+    assert!(!constant_time_contains(b"fourth", &candidates));
+    assert!(!constant_time_contains(b"first", &[]));
+}
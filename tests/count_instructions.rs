@@ -2,7 +2,7 @@
 mod tests {
     use std::io::Result;
 
-    use constant_time_eq::{constant_time_eq, constant_time_eq_n};
+    use constant_time_eq::{constant_time_eq, constant_time_eq_n, constant_time_eq_padded};
     use count_instructions::{Address, count_instructions};
 
     fn detect_features() {
@@ -133,6 +133,38 @@ mod tests {
         count_instructions_test_n::<64>()
     }
 
+    #[inline(never)]
+    fn count_padded(l: &[u8], r: &[u8], max_len: usize, capacity: usize) -> Result<Vec<Address>> {
+        let mut addresses = Vec::with_capacity(capacity);
+        assert!(!count_instructions(
+            || constant_time_eq_padded(l, r, max_len),
+            |instruction| addresses.push(instruction.address())
+        )?);
+        Ok(addresses)
+    }
+
+    #[test]
+    fn count_instructions_test_padded() -> Result<()> {
+        detect_features();
+
+        const N: usize = 64;
+        let l = [b'A'; N];
+        let r = [b'B'; N];
+        let baseline = count_padded(&l[..1], &r[..1], N, 0)?;
+
+        // The number of instructions must depend only on max_len, not on the input lengths.
+        for m in 0..=N {
+            for n in 0..=N {
+                assert_eq!(count_padded(&l[..m], &r[..n], N, baseline.len())?, baseline);
+                assert_eq!(count_padded(&l[..m], &l[..n], N, baseline.len())?, baseline);
+            }
+        }
+
+        assert!(constant_time_eq_padded(&l, &l, N));
+
+        Ok(())
+    }
+
     // This silly test shows that count_instructions() can detect early returns.
     #[test]
     fn count_instructions_test_variable() -> Result<()> {
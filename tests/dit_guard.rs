@@ -0,0 +1,36 @@
+#![cfg(not(miri))]
+
+use constant_time_eq::{DitGuard, constant_time_eq, with_data_independent_timing};
+
+#[test]
+fn test_with_data_independent_timing_nested() {
+    let result = with_data_independent_timing(|| {
+        let inner = with_data_independent_timing(|| constant_time_eq(b"foo", b"foo"));
+        inner && !constant_time_eq(b"foo", b"bar")
+    });
+    assert!(result);
+}
+
+#[test]
+fn test_dit_guard_nested() {
+    let outer = DitGuard::new();
+    {
+        let _inner = outer.nest();
+        assert!(with_data_independent_timing(|| constant_time_eq(
+            b"foo", b"foo"
+        )));
+    }
+    assert!(!constant_time_eq(b"foo", b"bar"));
+    drop(outer);
+    assert!(constant_time_eq(b"foo", b"foo"));
+}
+
+#[test]
+fn test_with_data_independent_timing_panic() {
+    let result = std::panic::catch_unwind(|| {
+        let _guard = DitGuard::new();
+        with_data_independent_timing(|| panic!("expected"))
+    });
+    assert!(result.is_err());
+    assert!(constant_time_eq(b"foo", b"foo"));
+}
@@ -0,0 +1,107 @@
+#![cfg(not(miri))]
+
+use constant_time_eq::{Unsigned, constant_time_eq_elements};
+
+/// Confirms that all bit positions are being used for comparison, for all lengths up to 40 elements.
+fn test_all_lengths<T, F>(fill: F, constant_time_eq_elements: fn(&[T], &[T]) -> bool)
+where
+    T: Unsigned + core::ops::BitXorAssign + core::ops::Shl<u32, Output = T> + From<u8>,
+    F: Fn(usize) -> T,
+{
+    let bits = 8 * size_of::<T>() as u32;
+    let a: Vec<T> = (0..40).map(&fill).collect();
+    let mut b = a.clone();
+
+    assert!(!constant_time_eq_elements(&a[..39], &b));
+    assert!(!constant_time_eq_elements(&a, &b[..39]));
+
+    for n in 0..=40 {
+        let a = &a[..n];
+        let b = &mut b[..n];
+
+        assert!(constant_time_eq_elements(a, b));
+        for i in 0..n {
+            for bit in 0..bits {
+                let m = T::from(1) << bit;
+                b[i] ^= m;
+                assert!(
+                    !constant_time_eq_elements(a, b),
+                    "len={} b[{}] bit {}",
+                    n,
+                    i,
+                    bit
+                );
+                b[i] ^= m;
+            }
+        }
+        assert!(constant_time_eq_elements(a, b));
+    }
+}
+
+#[test]
+fn test_elements_u8() {
+    test_all_lengths(|i| i as u8, constant_time_eq_elements);
+    test_all_lengths(
+        |i| i as u8,
+        constant_time_eq::generic::constant_time_eq_elements,
+    );
+}
+
+#[test]
+fn test_elements_u16() {
+    test_all_lengths(|i| i as u16 * 0x0101, constant_time_eq_elements);
+    test_all_lengths(
+        |i| i as u16 * 0x0101,
+        constant_time_eq::generic::constant_time_eq_elements,
+    );
+}
+
+#[test]
+fn test_elements_u32() {
+    test_all_lengths(|i| i as u32 * 0x01010101, constant_time_eq_elements);
+    test_all_lengths(
+        |i| i as u32 * 0x01010101,
+        constant_time_eq::generic::constant_time_eq_elements,
+    );
+}
+
+#[test]
+fn test_elements_u64() {
+    test_all_lengths(|i| !(i as u64), constant_time_eq_elements);
+    test_all_lengths(
+        |i| !(i as u64),
+        constant_time_eq::generic::constant_time_eq_elements,
+    );
+}
+
+#[test]
+fn test_elements_u128() {
+    test_all_lengths(|i| (i as u128) << 64, constant_time_eq_elements);
+    test_all_lengths(
+        |i| (i as u128) << 64,
+        constant_time_eq::generic::constant_time_eq_elements,
+    );
+}
+
+#[test]
+fn test_elements_usize() {
+    test_all_lengths(|i| !i, constant_time_eq_elements);
+    test_all_lengths(|i| !i, constant_time_eq::generic::constant_time_eq_elements);
+}
+
+#[test]
+fn test_elements_n() {
+    use constant_time_eq::constant_time_eq_elements_n;
+
+    let a = [0x0123_4567_89AB_CDEFu64; 4];
+    let mut b = a;
+    assert!(constant_time_eq_elements_n(&a, &b));
+    assert!(constant_time_eq::generic::constant_time_eq_elements_n(
+        &a, &b
+    ));
+    b[3] ^= 1 << 63;
+    assert!(!constant_time_eq_elements_n(&a, &b));
+    assert!(!constant_time_eq::generic::constant_time_eq_elements_n(
+        &a, &b
+    ));
+}
@@ -118,3 +118,45 @@ fn exhaustive_test_random_generic() {
     use constant_time_eq::generic::constant_time_eq;
     exhaustive_test_random(&constant_time_eq);
 }
+
+#[test]
+fn exhaustive_test_random_mask_simd() {
+    use constant_time_eq::{constant_time_eq_mask, constant_time_eq_mask_u64};
+    exhaustive_test_random(&|a: &[u8], b: &[u8]| {
+        let mask = constant_time_eq_mask(a, b);
+        assert!(mask == 0 || mask == !0);
+        assert_eq!(
+            u64::from(mask & 1).wrapping_neg(),
+            constant_time_eq_mask_u64(a, b)
+        );
+        mask != 0
+    });
+}
+
+#[test]
+fn exhaustive_test_random_mask_generic() {
+    use constant_time_eq::generic::{constant_time_eq_mask, constant_time_eq_mask_u64};
+    exhaustive_test_random(&|a: &[u8], b: &[u8]| {
+        let mask = constant_time_eq_mask(a, b);
+        assert!(mask == 0 || mask == !0);
+        assert_eq!(
+            u64::from(mask & 1).wrapping_neg(),
+            constant_time_eq_mask_u64(a, b)
+        );
+        mask != 0
+    });
+}
+
+#[test]
+fn exhaustive_test_random_masked_simd() {
+    use constant_time_eq::constant_time_eq_masked;
+    let mask = [0xFF; 128];
+    exhaustive_test_random(&|a: &[u8], b: &[u8]| constant_time_eq_masked(a, b, &mask[..a.len()]));
+}
+
+#[test]
+fn exhaustive_test_random_masked_generic() {
+    use constant_time_eq::generic::constant_time_eq_masked;
+    let mask = [0xFF; 128];
+    exhaustive_test_random(&|a: &[u8], b: &[u8]| constant_time_eq_masked(a, b, &mask[..a.len()]));
+}
@@ -0,0 +1,76 @@
+#![cfg(not(miri))]
+
+mod common;
+
+use common::fill_random;
+use constant_time_eq::constant_time_eq_hex;
+
+fn encode(raw: &[u8], upper: bool) -> Vec<u8> {
+    let digits: &[u8; 16] = if upper {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
+    raw.iter()
+        .flat_map(|&b| [digits[usize::from(b >> 4)], digits[usize::from(b & 0xF)]])
+        .collect()
+}
+
+/// Confirms that every pair of characters decodes to the expected byte, or is rejected.
+#[test]
+fn exhaustive_test_hex_pairs() {
+    for hi in 0..=255u8 {
+        for lo in 0..=255u8 {
+            let expected = match (char::from(hi).to_digit(16), char::from(lo).to_digit(16)) {
+                (Some(hi), Some(lo)) => Some((hi << 4 | lo) as u8),
+                _ => None,
+            };
+            for byte in [0x00, 0xFF, hi.wrapping_add(lo)]
+                .into_iter()
+                .chain(expected)
+            {
+                assert_eq!(
+                    constant_time_eq_hex(&[byte], &[hi, lo]),
+                    expected == Some(byte),
+                    "byte=0x{:02x} hex=[0x{:02x}, 0x{:02x}]",
+                    byte,
+                    hi,
+                    lo
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_all_lengths() {
+    let mut raw = [0u8; 64];
+    fill_random(&mut raw);
+
+    for n in 0..=raw.len() {
+        let raw = &mut raw[..n];
+        for upper in [false, true] {
+            let mut hex = encode(raw, upper);
+            assert!(constant_time_eq_hex(raw, &hex));
+
+            for i in 0..n {
+                raw[i] ^= 0x01;
+                assert!(!constant_time_eq_hex(raw, &hex), "len={} raw[{}]", n, i);
+                raw[i] ^= 0x01;
+            }
+
+            for i in 0..hex.len() {
+                let c = hex[i];
+                hex[i] = b'x';
+                assert!(!constant_time_eq_hex(raw, &hex), "len={} hex[{}]", n, i);
+                hex[i] = c;
+            }
+
+            if n > 0 {
+                assert!(!constant_time_eq_hex(raw, &hex[..hex.len() - 1]));
+            }
+            hex.push(b'0');
+            assert!(!constant_time_eq_hex(raw, &hex));
+        }
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(not(miri))]
+
+/// Confirms that every pair of byte values is folded correctly, in every position.
+fn test_all_bytes<CTEQ>(cteq: &CTEQ)
+where
+    CTEQ: Fn(&[u8], &[u8]) -> bool,
+{
+    // The length covers the vector loop, the word loop, and the leftover bytes.
+    const N: usize = 16 + 8 + 7;
+    let base = [b'q'; N + 1];
+
+    for x in 0..=255u8 {
+        for y in 0..=255u8 {
+            let expected = x.eq_ignore_ascii_case(&y);
+            for i in [0, 15, 16, 23, 24, N - 1] {
+                let mut a = base;
+                let mut b = base;
+                a[1 + i] = x;
+                b[1 + i] = y;
+
+                // Use an offset of one byte, to ensure no SIMD load instructions require alignment.
+                assert_eq!(
+                    cteq(&a[1..], &b[1..]),
+                    expected,
+                    "x=0x{:02x} y=0x{:02x} i={}",
+                    x,
+                    y,
+                    i
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_all_bytes_simd() {
+    use constant_time_eq::constant_time_eq_ignore_ascii_case;
+    test_all_bytes(&constant_time_eq_ignore_ascii_case);
+}
+
+#[test]
+fn test_all_bytes_generic() {
+    use constant_time_eq::generic::constant_time_eq_ignore_ascii_case;
+    test_all_bytes(&constant_time_eq_ignore_ascii_case);
+}
+
+#[test]
+fn test_all_lengths() {
+    use constant_time_eq::constant_time_eq_ignore_ascii_case;
+
+    let upper: Vec<u8> = (0..100).map(|i| b'A' + (i % 26) as u8).collect();
+    let lower = upper.to_ascii_lowercase();
+    for n in 0..=100 {
+        assert!(constant_time_eq_ignore_ascii_case(&upper[..n], &lower[..n]));
+        if n > 0 {
+            assert!(!constant_time_eq_ignore_ascii_case(
+                &upper[..n],
+                &lower[..n - 1]
+            ));
+        }
+    }
+}
@@ -0,0 +1,53 @@
+#![cfg(not(miri))]
+
+use constant_time_eq::{
+    ct_ge, ct_ge_mask, ct_gt, ct_gt_mask, ct_le, ct_le_mask, ct_lt, ct_lt_mask,
+};
+
+#[test]
+fn exhaustive_test_u8() {
+    for a in 0..=255u8 {
+        for b in 0..=255u8 {
+            assert_eq!(ct_lt(a, b), a < b, "{} < {}", a, b);
+            assert_eq!(ct_le(a, b), a <= b, "{} <= {}", a, b);
+            assert_eq!(ct_gt(a, b), a > b, "{} > {}", a, b);
+            assert_eq!(ct_ge(a, b), a >= b, "{} >= {}", a, b);
+            assert_eq!(ct_lt_mask(a, b), if a < b { 0xFF } else { 0 });
+            assert_eq!(ct_le_mask(a, b), if a <= b { 0xFF } else { 0 });
+            assert_eq!(ct_gt_mask(a, b), if a > b { 0xFF } else { 0 });
+            assert_eq!(ct_ge_mask(a, b), if a >= b { 0xFF } else { 0 });
+        }
+    }
+}
+
+macro_rules! test_edge_values {
+    ($($name:ident: $t:ty),*) => {
+        $(
+            /// Confirms the results around zero, the maximum, and the sign bit of a signed type.
+            #[test]
+            fn $name() {
+                let half = <$t>::MAX / 2;
+                let values = [0, 1, 2, half - 1, half, half + 1, half + 2, <$t>::MAX - 1, <$t>::MAX];
+                for a in values {
+                    for b in values {
+                        assert_eq!(ct_lt(a, b), a < b, "{} < {}", a, b);
+                        assert_eq!(ct_le(a, b), a <= b, "{} <= {}", a, b);
+                        assert_eq!(ct_gt(a, b), a > b, "{} > {}", a, b);
+                        assert_eq!(ct_ge(a, b), a >= b, "{} >= {}", a, b);
+                        assert_eq!(ct_lt_mask(a, b), if a < b { <$t>::MAX } else { 0 });
+                        assert_eq!(ct_ge_mask(a, b), if a >= b { <$t>::MAX } else { 0 });
+                    }
+                }
+            }
+        )*
+    };
+}
+
+test_edge_values!(
+    test_edge_values_u8: u8,
+    test_edge_values_u16: u16,
+    test_edge_values_u32: u32,
+    test_edge_values_u64: u64,
+    test_edge_values_u128: u128,
+    test_edge_values_usize: usize
+);
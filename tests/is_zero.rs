@@ -0,0 +1,47 @@
+#![cfg(not(miri))]
+
+/// Confirms that all bit positions are being checked, for all lengths up to 1024 bits.
+fn test_all_lengths<CTZ>(ctz: &CTZ)
+where
+    CTZ: Fn(&[u8]) -> bool,
+{
+    let mut buf = [0u8; 129];
+
+    // Note: this is quadratic; do not increase the maximum length too much.
+    for n in 0..=128 {
+        // Use an offset of one byte, to ensure no SIMD load instructions require alignment.
+        let a = &mut buf[1..=n];
+
+        assert!(ctz(a));
+        for i in 0..n {
+            for m in [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80] {
+                a[i] ^= m;
+                assert!(!ctz(a), "len={} a[{}] mask 0x{:02x}", n, i, m);
+                a[i] ^= m;
+            }
+        }
+        assert!(ctz(a));
+    }
+}
+
+#[test]
+fn exhaustive_test_is_zero_simd() {
+    use constant_time_eq::constant_time_is_zero;
+    test_all_lengths(&constant_time_is_zero);
+}
+
+#[test]
+fn exhaustive_test_is_zero_generic() {
+    use constant_time_eq::generic::constant_time_is_zero;
+    test_all_lengths(&constant_time_is_zero);
+}
+
+#[test]
+fn test_is_zero_n() {
+    use constant_time_eq::constant_time_is_zero_n;
+
+    let mut a = [0u8; 48];
+    assert!(constant_time_is_zero_n(&a));
+    a[47] = 0x80;
+    assert!(!constant_time_is_zero_n(&a));
+}
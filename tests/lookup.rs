@@ -0,0 +1,49 @@
+#![cfg(not(miri))]
+
+mod common;
+
+use common::fill_random;
+
+/// Confirms that every index reads its own entry, and out of bounds indexes read zeros.
+fn test_all_indexes<const W: usize, CTLOOKUP>(ctlookup: &CTLOOKUP)
+where
+    CTLOOKUP: Fn(&[[u8; W]], usize, &mut [u8; W]),
+{
+    let mut table = [[0u8; W]; 67];
+    for entry in &mut table {
+        fill_random(entry);
+        entry[0] ^= 0x55;
+    }
+
+    for n in [0, 1, 2, 16, 67] {
+        let table = &table[..n];
+        for index in 0..n {
+            let mut out = [0xAA; W];
+            ctlookup(table, index, &mut out);
+            assert_eq!(out, table[index], "len={} index={}", n, index);
+        }
+        for index in [n, n + 1, usize::MAX] {
+            let mut out = [0xAA; W];
+            ctlookup(table, index, &mut out);
+            assert_eq!(out, [0; W], "len={} index={}", n, index);
+        }
+    }
+}
+
+#[test]
+fn test_lookup_simd() {
+    use constant_time_eq::constant_time_lookup;
+    test_all_indexes::<1, _>(&constant_time_lookup);
+    test_all_indexes::<16, _>(&constant_time_lookup);
+    test_all_indexes::<35, _>(&constant_time_lookup);
+    test_all_indexes::<96, _>(&constant_time_lookup);
+}
+
+#[test]
+fn test_lookup_generic() {
+    use constant_time_eq::generic::constant_time_lookup;
+    test_all_indexes::<1, _>(&constant_time_lookup);
+    test_all_indexes::<16, _>(&constant_time_lookup);
+    test_all_indexes::<35, _>(&constant_time_lookup);
+    test_all_indexes::<96, _>(&constant_time_lookup);
+}
@@ -0,0 +1,63 @@
+#![cfg(not(miri))]
+
+mod common;
+
+use common::fill_random;
+use constant_time_eq::{constant_time_lt_be_bytes, constant_time_lt_le_bytes};
+
+/// Compares two little-endian numbers, starting from the most significant byte.
+fn reference_lt_le(a: &[u8], b: &[u8]) -> bool {
+    a.iter().rev().lt(b.iter().rev())
+}
+
+#[test]
+fn exhaustive_test_one_and_two_bytes() {
+    for a in 0..=255u8 {
+        for b in 0..=255u8 {
+            assert_eq!(constant_time_lt_le_bytes(&[a], &[b]), a < b);
+            assert_eq!(constant_time_lt_be_bytes(&[a], &[b]), a < b);
+        }
+    }
+
+    // Covers every combination of borrows between two bytes.
+    let values = [0x00, 0x01, 0x7F, 0x80, 0x81, 0xFE, 0xFF];
+    for a in values.iter().flat_map(|&hi| values.map(|lo| [lo, hi])) {
+        for b in values.iter().flat_map(|&hi| values.map(|lo| [lo, hi])) {
+            let expected = u16::from_le_bytes(a) < u16::from_le_bytes(b);
+            assert_eq!(
+                constant_time_lt_le_bytes(&a, &b),
+                expected,
+                "{:?} < {:?}",
+                a,
+                b
+            );
+            assert_eq!(
+                constant_time_lt_be_bytes(&[a[1], a[0]], &[b[1], b[0]]),
+                expected
+            );
+        }
+    }
+}
+
+/// Confirms that a difference in any byte, in either direction, decides the result.
+#[test]
+fn test_32_bytes() {
+    let mut a = [0u8; 32];
+    fill_random(&mut a);
+
+    for i in 0..32 {
+        for delta in [1, 0x80, 0xFF] {
+            let mut b = a;
+            b[i] = b[i].wrapping_add(delta);
+            for (x, y) in [(&a, &b), (&b, &a), (&a, &a)] {
+                assert_eq!(
+                    constant_time_lt_le_bytes(x, y),
+                    reference_lt_le(x, y),
+                    "i={}",
+                    i
+                );
+                assert_eq!(constant_time_lt_be_bytes(x, y), x < y, "i={}", i);
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+#![cfg(not(miri))]
+
+/// Confirms that the bits not set in the mask are ignored, for all lengths up to 1024 bits.
+fn test_ignored_bits<CTEQ>(cteq: &CTEQ)
+where
+    CTEQ: Fn(&[u8], &[u8], &[u8]) -> bool,
+{
+    let a = [0x5Au8; 129];
+    let mut b = a;
+    let mut mask = [0xFFu8; 129];
+
+    // Note: this is quadratic; do not increase the maximum length too much.
+    for n in 0..=128 {
+        // Use an offset of one byte, to ensure no SIMD load instructions require alignment.
+        let a = &a[1..=n];
+        let b = &mut b[1..=n];
+        let mask = &mut mask[1..=n];
+
+        for i in 0..n {
+            for m in [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80] {
+                b[i] ^= m;
+                assert!(!cteq(a, b, mask), "len={} b[{}] mask 0x{:02x}", n, i, m);
+                mask[i] ^= m;
+                assert!(cteq(a, b, mask), "len={} b[{}] mask 0x{:02x}", n, i, m);
+                mask[i] ^= m;
+                b[i] ^= m;
+            }
+        }
+        assert!(cteq(a, b, mask));
+    }
+}
+
+#[test]
+fn test_ignored_bits_simd() {
+    use constant_time_eq::constant_time_eq_masked;
+    test_ignored_bits(&constant_time_eq_masked);
+}
+
+#[test]
+fn test_ignored_bits_generic() {
+    use constant_time_eq::generic::constant_time_eq_masked;
+    test_ignored_bits(&constant_time_eq_masked);
+}
+
+#[test]
+fn test_masked_n() {
+    use constant_time_eq::constant_time_eq_n_masked;
+
+    let mut mask = [0xFF; 32];
+    mask[31] = 0xF0;
+    let a = [0x11; 32];
+    let mut b = a;
+    b[31] = 0x1F;
+    assert!(constant_time_eq_n_masked(&a, &b, &mask));
+    b[31] = 0x21;
+    assert!(!constant_time_eq_n_masked(&a, &b, &mask));
+}
@@ -0,0 +1,35 @@
+#![cfg(not(miri))]
+
+use constant_time_eq::constant_time_eq_padded;
+
+#[test]
+fn test_padded_all_lengths() {
+    let a = [0x5Au8; 48];
+    let mut b = a;
+    b[40] = 0xA5;
+
+    for max_len in [0, 1, 7, 8, 9, 16, 33, 48] {
+        for la in 0..=48 {
+            for lb in 0..=48 {
+                let (x, y) = (&a[..la], &b[..lb]);
+                let expected = x == y && la <= max_len;
+                assert_eq!(
+                    constant_time_eq_padded(x, y, max_len),
+                    expected,
+                    "la={} lb={} max_len={}",
+                    la,
+                    lb,
+                    max_len
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_padded_trailing_zeros() {
+    // Padding with zeros must not make inputs of different lengths equal.
+    assert!(!constant_time_eq_padded(b"key\0\0", b"key", 8));
+    assert!(!constant_time_eq_padded(b"", b"\0", 8));
+    assert!(constant_time_eq_padded(b"key\0\0", b"key\0\0", 8));
+}
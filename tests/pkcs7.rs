@@ -0,0 +1,82 @@
+#![cfg(not(miri))]
+
+use constant_time_eq::constant_time_pkcs7_unpad;
+
+/// Checks the padding the usual way, branching on the padding length.
+fn reference_unpad(block: &[u8], block_size: usize) -> Option<usize> {
+    if block.is_empty() || !block.len().is_multiple_of(block_size) {
+        return None;
+    }
+    let pad = usize::from(*block.last().unwrap());
+    if pad == 0 || pad > block_size {
+        return None;
+    }
+    let (data, padding) = block.split_at(block.len() - pad);
+    padding
+        .iter()
+        .all(|&b| usize::from(b) == pad)
+        .then_some(data.len())
+}
+
+/// Confirms that every padding length is checked, including one wrong byte at every position.
+#[test]
+fn exhaustive_test_pkcs7() {
+    for block_size in [1, 8, 16, 32, 255] {
+        for blocks in 1..=2 {
+            let len = block_size * blocks;
+            for pad in 0..=255u8 {
+                let mut block = vec![0x41; len];
+                let start = len.saturating_sub(usize::from(pad));
+                block[start..].fill(pad);
+
+                let expected = reference_unpad(&block, block_size);
+                let (mask, unpadded) = constant_time_pkcs7_unpad(&block, block_size);
+                assert_eq!(
+                    (mask, unpadded),
+                    expected.map_or((0x00, 0), |len| (0xFF, len)),
+                    "block_size={} len={} pad={}",
+                    block_size,
+                    len,
+                    pad
+                );
+
+                // A wrong byte anywhere in the last block must invalidate the padding only when
+                // it is part of the padding.
+                for i in len - block_size..len - 1 {
+                    block[i] ^= 0x80;
+                    let expected = reference_unpad(&block, block_size);
+                    assert_eq!(
+                        constant_time_pkcs7_unpad(&block, block_size),
+                        expected.map_or((0x00, 0), |len| (0xFF, len)),
+                        "block_size={} len={} pad={} i={}",
+                        block_size,
+                        len,
+                        pad,
+                        i
+                    );
+                    block[i] ^= 0x80;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pkcs7_wrong_length() {
+    assert_eq!(constant_time_pkcs7_unpad(b"", 16), (0x00, 0));
+    assert_eq!(constant_time_pkcs7_unpad(&[1; 15], 16), (0x00, 0));
+    assert_eq!(constant_time_pkcs7_unpad(&[1; 17], 16), (0x00, 0));
+    assert_eq!(constant_time_pkcs7_unpad(&[1; 32], 16), (0xFF, 31));
+}
+
+#[test]
+#[should_panic]
+fn test_pkcs7_block_size_zero() {
+    let _ = constant_time_pkcs7_unpad(&[1; 16], 0);
+}
+
+#[test]
+#[should_panic]
+fn test_pkcs7_block_size_too_large() {
+    let _ = constant_time_pkcs7_unpad(&[1; 256], 256);
+}
@@ -0,0 +1,42 @@
+#![cfg(not(miri))]
+
+use constant_time_eq::{constant_time_ends_with, constant_time_starts_with};
+
+#[test]
+fn test_starts_with_ends_with() {
+    let mut haystack = [0u8; 80];
+    for (i, byte) in haystack.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+
+    for n in 0..=80 {
+        for len in 0..=n + 1 {
+            let needle = &haystack[..len.min(80)];
+            assert_eq!(
+                constant_time_starts_with(&haystack[..n], needle),
+                haystack[..n].starts_with(needle),
+                "n={} len={}",
+                n,
+                len
+            );
+
+            let needle = &haystack[80 - len.min(80)..];
+            let tail = &haystack[80 - n..];
+            assert_eq!(
+                constant_time_ends_with(tail, needle),
+                tail.ends_with(needle),
+                "n={} len={}",
+                n,
+                len
+            );
+        }
+    }
+
+    let mut prefix = haystack[..40].to_vec();
+    prefix[39] ^= 0x80;
+    assert!(!constant_time_starts_with(&haystack, &prefix));
+
+    let mut suffix = haystack[40..].to_vec();
+    suffix[0] ^= 0x01;
+    assert!(!constant_time_ends_with(&haystack, &suffix));
+}
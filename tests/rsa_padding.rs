@@ -0,0 +1,117 @@
+#![cfg(not(miri))]
+
+use constant_time_eq::{constant_time_oaep_unpad, constant_time_pkcs1v15_unpad};
+
+/// Checks PKCS#1 v1.5 padding the usual way, returning early on the first problem.
+fn reference_pkcs1v15(em: &[u8]) -> Option<usize> {
+    if em.len() < 11 || em[0] != 0x00 || em[1] != 0x02 {
+        return None;
+    }
+    let sep = 2 + em[2..].iter().position(|&b| b == 0)?;
+    (sep >= 10).then_some(sep + 1)
+}
+
+/// Checks OAEP padding the usual way, returning early on the first problem.
+fn reference_oaep(em: &[u8], label_hash: &[u8]) -> Option<usize> {
+    let h = label_hash.len();
+    if em.len() < 2 * h + 2 || em[0] != 0x00 || &em[1 + h..1 + 2 * h] != label_hash {
+        return None;
+    }
+    let sep = 1 + 2 * h + em[1 + 2 * h..].iter().position(|&b| b != 0)?;
+    (em[sep] == 0x01).then_some(sep + 1)
+}
+
+fn expected(result: Option<usize>) -> (u8, usize) {
+    result.map_or((0x00, 0), |offset| (0xFF, offset))
+}
+
+/// Confirms the result for every separator position, and for every byte changed at every position.
+#[test]
+fn exhaustive_test_pkcs1v15() {
+    for len in [11, 12, 32, 64] {
+        for sep in 2..len {
+            let mut em = vec![0x5a; len];
+            em[0] = 0x00;
+            em[1] = 0x02;
+            em[sep] = 0x00;
+            assert_eq!(
+                constant_time_pkcs1v15_unpad(&em),
+                expected(reference_pkcs1v15(&em)),
+                "len={} sep={}",
+                len,
+                sep
+            );
+
+            for i in 0..len {
+                for value in [0x00, 0x01, 0x02, 0x80] {
+                    let orig = em[i];
+                    em[i] = value;
+                    assert_eq!(
+                        constant_time_pkcs1v15_unpad(&em),
+                        expected(reference_pkcs1v15(&em)),
+                        "len={} sep={} em[{}]=0x{:02x}",
+                        len,
+                        sep,
+                        i,
+                        value
+                    );
+                    em[i] = orig;
+                }
+            }
+        }
+    }
+
+    for len in 0..11 {
+        assert_eq!(constant_time_pkcs1v15_unpad(&vec![0x02; len]), (0x00, 0));
+    }
+}
+
+/// Confirms the result for every separator position, and for every byte changed at every position.
+#[test]
+fn exhaustive_test_oaep() {
+    for h in [0, 1, 4, 20] {
+        let label_hash: Vec<u8> = (0..h as u8).map(|i| 0x40 + i).collect();
+        for len in [2 * h + 2, 2 * h + 3, 2 * h + 40] {
+            for sep in 1 + 2 * h..len {
+                let mut em = vec![0x5a; len];
+                em[0] = 0x00;
+                em[1 + h..1 + 2 * h].copy_from_slice(&label_hash);
+                em[1 + 2 * h..sep].fill(0x00);
+                em[sep] = 0x01;
+                assert_eq!(
+                    constant_time_oaep_unpad(&em, &label_hash),
+                    expected(reference_oaep(&em, &label_hash)),
+                    "h={} len={} sep={}",
+                    h,
+                    len,
+                    sep
+                );
+
+                for i in 0..len {
+                    for value in [0x00, 0x01, 0x02, 0x80] {
+                        let orig = em[i];
+                        em[i] = value;
+                        assert_eq!(
+                            constant_time_oaep_unpad(&em, &label_hash),
+                            expected(reference_oaep(&em, &label_hash)),
+                            "h={} len={} sep={} em[{}]=0x{:02x}",
+                            h,
+                            len,
+                            sep,
+                            i,
+                            value
+                        );
+                        em[i] = orig;
+                    }
+                }
+            }
+        }
+
+        for len in 0..2 * h + 2 {
+            assert_eq!(
+                constant_time_oaep_unpad(&vec![0x01; len], &label_hash),
+                (0x00, 0)
+            );
+        }
+    }
+}
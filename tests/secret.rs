@@ -0,0 +1,35 @@
+#![cfg(not(miri))]
+
+use constant_time_eq::{ConstantTimeEq, Secret};
+
+#[test]
+fn test_secret() {
+    let mut bytes = [0x42; 32];
+    let key = Secret::new(bytes);
+    assert_eq!(key, Secret::from(bytes));
+    assert!(key.ct_eq(&Secret::from(bytes)));
+
+    bytes[31] ^= 1;
+    assert_ne!(key, Secret::new(bytes));
+    assert!(key.ct_ne(&Secret::new(bytes)));
+
+    assert_eq!(key.expose_secret(), &[0x42; 32]);
+    assert_eq!(format!("{:?}", key), "Secret<32>(..)");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_secret_bytes() {
+    use constant_time_eq::SecretBytes;
+
+    let token = SecretBytes::from(&b"token"[..]);
+    assert_eq!(token, SecretBytes::from(b"token".to_vec()));
+    assert_eq!(token, SecretBytes::new(Box::from(&b"token"[..])));
+    assert!(token.ct_eq(&token.clone()));
+
+    assert_ne!(token, SecretBytes::from(&b"tokeN"[..]));
+    assert_ne!(token, SecretBytes::from(&b"toke"[..]));
+
+    assert_eq!(token.expose_secret(), b"token");
+    assert_eq!(format!("{:?}", token), "SecretBytes(..)");
+}
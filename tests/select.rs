@@ -0,0 +1,97 @@
+#![cfg(not(miri))]
+
+mod common;
+
+use common::fill_random;
+
+/// Confirms that every byte is selected bit by bit, for all lengths up to 1024 bits.
+fn test_select_all_lengths<CTSELECT>(ctselect: &CTSELECT)
+where
+    CTSELECT: Fn(u8, &[u8], &[u8], &mut [u8]),
+{
+    let mut buf = [0u8; 2 * 129];
+    fill_random(&mut buf);
+    let (a, b) = buf.split_at(129);
+
+    for n in 0..=128 {
+        // Use an offset of one byte, to ensure no SIMD load instructions require alignment.
+        let a = &a[1..=n];
+        let b = &b[1..=n];
+        let mut out = [0u8; 129];
+        let out = &mut out[1..=n];
+
+        for mask in [0x00, 0xFF, 0x0F, 0xA5] {
+            ctselect(mask, a, b, out);
+            for i in 0..n {
+                assert_eq!(
+                    out[i],
+                    (a[i] & mask) | (b[i] & !mask),
+                    "len={} mask=0x{:02x}",
+                    n,
+                    mask
+                );
+            }
+        }
+    }
+}
+
+/// Confirms that the copy happens only when the condition is true, for all lengths up to 1024 bits.
+fn test_copy_if_all_lengths<CTCOPYIF>(ctcopyif: &CTCOPYIF)
+where
+    CTCOPYIF: Fn(bool, &mut [u8], &[u8]),
+{
+    let mut buf = [0u8; 2 * 129];
+    fill_random(&mut buf);
+    let (a, b) = buf.split_at(129);
+
+    for n in 0..=128 {
+        // Use an offset of one byte, to ensure no SIMD load instructions require alignment.
+        let mut dst = [0u8; 129];
+        let dst = &mut dst[1..=n];
+        dst.copy_from_slice(&a[1..=n]);
+        let src = &b[1..=n];
+
+        ctcopyif(false, dst, src);
+        assert_eq!(dst, &a[1..=n], "len={}", n);
+        ctcopyif(true, dst, src);
+        assert_eq!(dst, src, "len={}", n);
+    }
+}
+
+#[test]
+fn test_select_simd() {
+    use constant_time_eq::ct_select;
+    test_select_all_lengths(&ct_select);
+}
+
+#[test]
+fn test_select_generic() {
+    use constant_time_eq::generic::ct_select;
+    test_select_all_lengths(&ct_select);
+}
+
+#[test]
+fn test_copy_if_simd() {
+    use constant_time_eq::ct_copy_if;
+    test_copy_if_all_lengths(&ct_copy_if);
+}
+
+#[test]
+fn test_copy_if_generic() {
+    use constant_time_eq::generic::ct_copy_if;
+    test_copy_if_all_lengths(&ct_copy_if);
+}
+
+#[test]
+#[should_panic]
+fn test_select_length_mismatch() {
+    use constant_time_eq::ct_select;
+    ct_select(0xFF, b"foo", b"bar", &mut [0; 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_copy_if_length_mismatch() {
+    use constant_time_eq::ct_copy_if;
+    ct_copy_if(true, &mut [0; 4], b"foo");
+}
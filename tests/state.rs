@@ -0,0 +1,45 @@
+#![cfg(not(miri))]
+
+use constant_time_eq::ConstantTimeEqState;
+
+/// Compares both inputs in two chunks, split at the given position.
+fn compare_split(expected: &[u8], actual: &[u8], split: usize) -> bool {
+    let mut state = ConstantTimeEqState::new();
+    state.update(&expected[..split], &actual[..split]);
+    state.update(&expected[split..], &actual[split..]);
+    state.finalize()
+}
+
+#[test]
+fn test_state_all_splits() {
+    let expected: Vec<u8> = (0..100u8).collect();
+    let mut actual = expected.clone();
+
+    for split in 0..=100 {
+        assert!(compare_split(&expected, &actual, split));
+        for i in [0, 31, 32, 63, 99] {
+            actual[i] ^= 0x40;
+            assert!(
+                !compare_split(&expected, &actual, split),
+                "split={} i={}",
+                split,
+                i
+            );
+            actual[i] ^= 0x40;
+        }
+    }
+}
+
+#[test]
+fn test_state_chunk_length_mismatch() {
+    let mut state = ConstantTimeEqState::new();
+    state.update(b"foo", b"fo");
+    state.update(b"bar", b"obar");
+    assert!(!state.finalize());
+}
+
+#[test]
+fn test_state_empty() {
+    assert!(ConstantTimeEqState::new().finalize());
+    assert!(ConstantTimeEqState::default().finalize());
+}
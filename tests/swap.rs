@@ -0,0 +1,100 @@
+#![cfg(not(miri))]
+
+mod common;
+
+use common::fill_random;
+
+/// Confirms that the contents are exchanged only when the condition is true, for all lengths up
+/// to 1024 bits.
+fn test_all_lengths<CTSWAP>(ctswap: &CTSWAP)
+where
+    CTSWAP: Fn(bool, &mut [u8], &mut [u8]),
+{
+    let mut orig = [0u8; 2 * 129];
+    fill_random(&mut orig);
+    let (orig_a, orig_b) = orig.split_at(129);
+
+    for n in 0..=128 {
+        let mut a = [0u8; 129];
+        let mut b = [0u8; 129];
+
+        // Use an offset of one byte, to ensure no SIMD load instructions require alignment.
+        let a = &mut a[1..=n];
+        let b = &mut b[1..=n];
+        a.copy_from_slice(&orig_a[1..=n]);
+        b.copy_from_slice(&orig_b[1..=n]);
+
+        ctswap(false, a, b);
+        assert_eq!(a, &orig_a[1..=n], "len={}", n);
+        assert_eq!(b, &orig_b[1..=n], "len={}", n);
+
+        ctswap(true, a, b);
+        assert_eq!(a, &orig_b[1..=n], "len={}", n);
+        assert_eq!(b, &orig_a[1..=n], "len={}", n);
+    }
+}
+
+#[test]
+fn test_swap_simd() {
+    use constant_time_eq::ct_swap;
+    test_all_lengths(&ct_swap);
+}
+
+#[test]
+fn test_swap_generic() {
+    use constant_time_eq::generic::ct_swap;
+    test_all_lengths(&ct_swap);
+}
+
+/// Confirms that the elements are exchanged only when the condition is true, for all lengths up
+/// to 40 elements.
+fn test_elements_all_lengths<T, F>(fill: F, ct_swap_elements: fn(bool, &mut [T], &mut [T]))
+where
+    T: constant_time_eq::Unsigned + PartialEq + core::fmt::Debug,
+    F: Fn(usize) -> T,
+{
+    let orig_a: Vec<T> = (0..40).map(&fill).collect();
+    let orig_b: Vec<T> = (40..80).map(&fill).collect();
+
+    for n in 0..=40 {
+        let mut a = orig_a[..n].to_vec();
+        let mut b = orig_b[..n].to_vec();
+
+        ct_swap_elements(false, &mut a, &mut b);
+        assert_eq!(a, &orig_a[..n], "len={}", n);
+        assert_eq!(b, &orig_b[..n], "len={}", n);
+
+        ct_swap_elements(true, &mut a, &mut b);
+        assert_eq!(a, &orig_b[..n], "len={}", n);
+        assert_eq!(b, &orig_a[..n], "len={}", n);
+    }
+}
+
+#[test]
+fn test_swap_elements() {
+    use constant_time_eq::ct_swap_elements;
+    test_elements_all_lengths(|i| i as u8, ct_swap_elements);
+    test_elements_all_lengths(|i| i as u16 * 0x0101, ct_swap_elements);
+    test_elements_all_lengths(|i| i as u32 * 0x01010101, ct_swap_elements);
+    test_elements_all_lengths(|i| !(i as u64), ct_swap_elements);
+    test_elements_all_lengths(|i| u128::MAX - i as u128, ct_swap_elements);
+    test_elements_all_lengths(|i| !i, ct_swap_elements);
+}
+
+#[test]
+fn test_swap_elements_generic() {
+    use constant_time_eq::generic::ct_swap_elements;
+    test_elements_all_lengths(|i| i as u8, ct_swap_elements);
+    test_elements_all_lengths(|i| i as u16 * 0x0101, ct_swap_elements);
+    test_elements_all_lengths(|i| i as u32 * 0x01010101, ct_swap_elements);
+    test_elements_all_lengths(|i| !(i as u64), ct_swap_elements);
+    test_elements_all_lengths(|i| u128::MAX - i as u128, ct_swap_elements);
+    test_elements_all_lengths(|i| !i, ct_swap_elements);
+}
+
+#[test]
+#[should_panic]
+fn test_swap_length_mismatch() {
+    use constant_time_eq::ct_swap;
+    ct_swap(true, &mut [0; 3], &mut [0; 4]);
+}
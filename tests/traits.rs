@@ -0,0 +1,37 @@
+#![cfg(not(miri))]
+
+use constant_time_eq::ConstantTimeEq;
+
+#[test]
+fn test_ct_eq_core() {
+    assert!(b"foo"[..].ct_eq(&b"foo"[..]));
+    assert!(b"foo"[..].ct_ne(&b"bar"[..]));
+    assert!(b"foo"[..].ct_ne(&b"fo"[..]));
+
+    assert!([3u8; 32].ct_eq(&[3; 32]));
+    assert!([3u8; 32].ct_ne(&[7; 32]));
+
+    assert!("foo".ct_eq("foo"));
+    assert!("foo".ct_ne("bar"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ct_eq_std() {
+    use std::borrow::Cow;
+
+    assert!(vec![1u8, 2, 3].ct_eq(&vec![1, 2, 3]));
+    assert!(vec![1u8, 2, 3].ct_ne(&vec![1, 2, 4]));
+
+    let boxed: Box<[u8]> = Box::new([1, 2, 3]);
+    assert!(boxed.ct_eq(&boxed.clone()));
+    assert!(boxed.ct_ne(&Box::from(&[1u8, 2][..])));
+
+    assert!(String::from("foo").ct_eq(&String::from("foo")));
+    assert!(String::from("foo").ct_ne(&String::from("bar")));
+
+    let borrowed: Cow<[u8]> = Cow::Borrowed(b"foo");
+    let owned: Cow<[u8]> = Cow::Owned(b"foo".to_vec());
+    assert!(borrowed.ct_eq(&owned));
+    assert!(borrowed.ct_ne(&Cow::Borrowed(&b"bar"[..])));
+}
@@ -0,0 +1,47 @@
+#![cfg(all(feature = "std", not(miri)))]
+
+use std::io::Write;
+
+use constant_time_eq::VerifyingWriter;
+
+/// Writes the input in chunks of the given size.
+fn verify_chunked(expected: &[u8], actual: &[u8], chunk_size: usize) -> bool {
+    let mut writer = VerifyingWriter::new(expected);
+    for chunk in actual.chunks(chunk_size) {
+        assert_eq!(writer.write(chunk).unwrap(), chunk.len());
+    }
+    writer.flush().unwrap();
+    writer.finish()
+}
+
+#[test]
+fn test_verifying_writer() {
+    let expected: Vec<u8> = (0..70u8).collect();
+    let mut actual = expected.clone();
+
+    for chunk_size in 1..=71 {
+        assert!(verify_chunked(&expected, &actual, chunk_size));
+
+        actual[33] ^= 0x02;
+        assert!(!verify_chunked(&expected, &actual, chunk_size));
+        actual[33] ^= 0x02;
+
+        // Underrun.
+        assert!(!verify_chunked(&expected, &actual[..69], chunk_size));
+
+        // Overrun.
+        actual.push(0);
+        assert!(!verify_chunked(&expected, &actual, chunk_size));
+        actual.pop();
+    }
+}
+
+#[test]
+fn test_verifying_writer_empty() {
+    assert!(VerifyingWriter::new(b"").finish());
+    assert!(!VerifyingWriter::new(b"foo").finish());
+
+    let mut writer = VerifyingWriter::new(b"");
+    writer.write_all(b"foo").unwrap();
+    assert!(!writer.finish());
+}